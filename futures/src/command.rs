@@ -56,6 +56,23 @@ impl<T> Command<T> {
         }
     }
 
+    /// Returns the amount of actions of the [`Command`].
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        let Command(command) = self;
+
+        match command {
+            Internal::None => 0,
+            Internal::Single(_) => 1,
+            Internal::Batch(batch) => batch.len(),
+        }
+    }
+
+    /// Returns whether the [`Command`] does nothing.
+    pub fn is_none(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Returns all of the actions of the [`Command`].
     pub fn actions(self) -> Vec<T> {
         let Command(command) = self;
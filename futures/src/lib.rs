@@ -17,20 +17,8 @@ mod command;
 mod runtime;
 
 pub mod executor;
+pub mod retry;
 pub mod subscription;
-
-#[cfg(all(
-    any(feature = "tokio", feature = "async-std", feature = "smol"),
-    not(target_arch = "wasm32")
-))]
-#[cfg_attr(
-    docsrs,
-    doc(cfg(any(
-        feature = "tokio",
-        feature = "async-std",
-        feature = "smol"
-    )))
-)]
 pub mod time;
 
 pub use command::Command;
@@ -0,0 +1,219 @@
+//! Retry fallible futures.
+use crate::time;
+
+use std::future::Future;
+use std::time::Duration;
+
+/// The policy used to [`retry`] a fallible future.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum amount of attempts, including the first one.
+    pub max_attempts: u32,
+
+    /// The delay before the first retry.
+    pub base_delay: Duration,
+
+    /// The factor applied to the delay after every retry.
+    ///
+    /// It should not be negative. A negative or NaN multiplier is treated
+    /// as `0.0`.
+    pub multiplier: f32,
+}
+
+impl RetryPolicy {
+    /// Returns the delay to wait after the given failed attempt.
+    ///
+    /// Attempts are counted starting from `1`. The delay saturates at the
+    /// longest delay a timer supports instead of overflowing: the maximum
+    /// [`Duration`], or `u32::MAX` milliseconds on Wasm.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        // `futures_timer` truncates delays to `u32` milliseconds on Wasm
+        #[cfg(target_arch = "wasm32")]
+        let maximum = Duration::from_millis(u64::from(u32::MAX));
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let maximum = Duration::new(u64::MAX, 999_999_999);
+
+        if self.base_delay.is_zero() {
+            return Duration::ZERO;
+        }
+
+        let retries = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let factor = f64::from(self.multiplier.max(0.0)).powi(retries);
+        let seconds = self.base_delay.as_secs_f64() * factor;
+
+        if seconds.is_nan() {
+            Duration::ZERO
+        } else if seconds < maximum.as_secs_f64() {
+            // `Duration::from_secs_f64` panics on overflow
+            Duration::from_secs_f64(seconds).min(maximum)
+        } else {
+            maximum
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            multiplier: 2.0,
+        }
+    }
+}
+
+/// The last error of a future that failed every attempt of a [`retry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Failure<E> {
+    /// The error produced by the last attempt.
+    pub error: E,
+
+    /// The amount of attempts performed.
+    pub attempts: u32,
+}
+
+/// Runs the futures created by the given `factory` until one of them
+/// succeeds, waiting between attempts as defined by the [`RetryPolicy`].
+///
+/// If every attempt fails, the last error is produced along with the amount
+/// of attempts performed.
+pub async fn retry<T, E, F>(
+    factory: impl Fn() -> F,
+    policy: RetryPolicy,
+) -> Result<T, Failure<E>>
+where
+    F: Future<Output = Result<T, E>>,
+{
+    let mut attempts = 0;
+
+    loop {
+        attempts += 1;
+
+        match factory().await {
+            Ok(value) => return Ok(value),
+            Err(error) if attempts >= policy.max_attempts => {
+                return Err(Failure { error, attempts });
+            }
+            Err(_) => time::delay(policy.delay(attempts)).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::executor::block_on;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_delay: Duration::from_millis(1),
+            multiplier: 2.0,
+        }
+    }
+
+    type Attempt = futures::future::Ready<Result<u32, u32>>;
+
+    fn failing(failures: u32, calls: &AtomicU32) -> impl Fn() -> Attempt + '_ {
+        move || {
+            let call = calls.fetch_add(1, Ordering::SeqCst);
+
+            futures::future::ready(if call < failures {
+                Err(call)
+            } else {
+                Ok(call)
+            })
+        }
+    }
+
+    #[test]
+    fn retry_until_success() {
+        let calls = AtomicU32::new(0);
+
+        let result = block_on(retry(failing(2, &calls), policy(3)));
+
+        assert_eq!(result, Ok(2));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn retry_produces_last_error() {
+        let calls = AtomicU32::new(0);
+
+        let result = block_on(retry(failing(5, &calls), policy(3)));
+
+        assert_eq!(
+            result,
+            Err(Failure {
+                error: 2,
+                attempts: 3
+            })
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn delay_grows_exponentially() {
+        let policy = RetryPolicy {
+            max_attempts: 5,
+            base_delay: Duration::from_secs(1),
+            multiplier: 2.0,
+        };
+
+        assert_eq!(policy.delay(1), Duration::from_secs(1));
+        assert_eq!(policy.delay(3), Duration::from_secs(4));
+    }
+
+    #[test]
+    fn delay_saturates() {
+        let policy = RetryPolicy {
+            max_attempts: 80,
+            base_delay: Duration::from_secs(1),
+            multiplier: 2.0,
+        };
+
+        let maximum = Duration::new(u64::MAX, 999_999_999);
+
+        assert_eq!(policy.delay(80), maximum);
+        assert_eq!(policy.delay(u32::MAX), maximum);
+    }
+
+    #[test]
+    fn negative_multiplier_is_zero() {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_secs(1),
+            multiplier: -2.0,
+        };
+
+        assert_eq!(policy.delay(1), Duration::from_secs(1));
+        assert_eq!(policy.delay(2), Duration::ZERO);
+    }
+
+    #[test]
+    fn zero_base_delay_stays_zero() {
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::ZERO,
+            multiplier: 2.0,
+        };
+
+        assert_eq!(policy.delay(1), Duration::ZERO);
+        assert_eq!(policy.delay(u32::MAX), Duration::ZERO);
+    }
+
+    #[test]
+    fn huge_attempt_saturates() {
+        let policy = RetryPolicy {
+            max_attempts: u32::MAX,
+            base_delay: Duration::from_millis(1),
+            multiplier: 1.5,
+        };
+
+        assert_eq!(policy.delay(u32::MAX), policy.delay(10_000));
+        assert!(policy.delay(u32::MAX) > policy.delay(10));
+    }
+}
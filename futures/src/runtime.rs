@@ -1,6 +1,6 @@
 //! Run commands and keep track of subscriptions.
-use crate::BoxFuture;
 use crate::{subscription, Executor, Subscription};
+use crate::{BoxFuture, BoxStream};
 
 use futures::{channel::mpsc, Sink};
 use std::marker::PhantomData;
@@ -66,6 +66,27 @@ where
         self.executor.spawn(future);
     }
 
+    /// Runs a stream in the [`Runtime`] until it ends.
+    ///
+    /// Every `Message` produced by the stream will be forwarded to the
+    /// `Sender` of the [`Runtime`], in order.
+    pub fn run(&mut self, stream: BoxStream<Message>) {
+        use futures::{FutureExt, StreamExt};
+
+        let sender = self.sender.clone();
+        let future = stream.map(Ok).forward(sender).map(|_| ());
+
+        self.executor.spawn(future);
+    }
+
+    /// Spawns a future in the [`Runtime`] without forwarding its output.
+    ///
+    /// This is useful when the future delivers its results to the `Sender`
+    /// by some other means.
+    pub fn spawn_detached(&mut self, future: BoxFuture<()>) {
+        self.executor.spawn(future);
+    }
+
     /// Tracks a [`Subscription`] in the [`Runtime`].
     ///
     /// It will spawn new streams or close old ones as necessary! See
@@ -1,17 +1,77 @@
 //! Listen and react to time.
+#[cfg(all(
+    any(feature = "tokio", feature = "async-std", feature = "smol"),
+    not(target_arch = "wasm32")
+))]
 use crate::subscription::{self, Subscription};
 
+use futures::future::{self, Either};
+use futures::stream::{self, Stream, StreamExt};
+use std::future::Future;
+use std::time::Duration;
+
+/// Returns a future that completes once the given `duration` has elapsed.
+///
+/// The timer does not depend on any particular [`Executor`], so the future
+/// can be run by any of them, including on the Web.
+///
+/// [`Executor`]: crate::Executor
+pub fn delay(duration: Duration) -> impl Future<Output = ()> {
+    futures_timer::Delay::new(duration)
+}
+
+/// Races a stream against a timer of the given `duration`.
+///
+/// The items of the stream are produced until it ends. If the timer fires
+/// first, `on_timeout` is produced instead and the stream is dropped.
+pub fn timeout<T>(
+    duration: Duration,
+    stream: impl Stream<Item = T> + Unpin,
+    on_timeout: T,
+) -> impl Stream<Item = T> {
+    let state = Some((stream, futures_timer::Delay::new(duration), on_timeout));
+
+    stream::unfold(state, |state| async move {
+        let (mut stream, timer, on_timeout) = state?;
+        let result = future::select(stream.next(), timer).await;
+
+        match result {
+            Either::Left((Some(output), timer)) => {
+                Some((output, Some((stream, timer, on_timeout))))
+            }
+            Either::Left((None, _)) => None,
+            Either::Right(_) => Some((on_timeout, None)),
+        }
+    })
+}
+
 /// Returns a [`Subscription`] that produces messages at a set interval.
 ///
 /// The first message is produced after a `duration`, and then continues to
 /// produce more messages every `duration` after that.
+#[cfg(all(
+    any(feature = "tokio", feature = "async-std", feature = "smol"),
+    not(target_arch = "wasm32")
+))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol"
+    )))
+)]
 pub fn every<H: std::hash::Hasher, E>(
-    duration: std::time::Duration,
+    duration: Duration,
 ) -> Subscription<H, E, std::time::Instant> {
     Subscription::from_recipe(Every(duration))
 }
 
-struct Every(std::time::Duration);
+#[cfg(all(
+    any(feature = "tokio", feature = "async-std", feature = "smol"),
+    not(target_arch = "wasm32")
+))]
+struct Every(Duration);
 
 #[cfg(all(
     not(any(feature = "tokio", feature = "async-std")),
@@ -103,3 +163,39 @@ where
         stream.map(tokio::time::Instant::into_std).boxed()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::executor::block_on;
+    use std::time::Instant;
+
+    #[test]
+    fn delay_waits_for_duration() {
+        let duration = Duration::from_millis(20);
+        let start = Instant::now();
+
+        block_on(delay(duration));
+
+        assert!(start.elapsed() >= duration);
+    }
+
+    #[test]
+    fn timeout_produces_items_of_finished_stream() {
+        let items = stream::iter(vec![1, 2, 3]);
+        let outputs: Vec<_> =
+            block_on(timeout(Duration::from_secs(60), items, 0).collect());
+
+        assert_eq!(outputs, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn timeout_interrupts_pending_stream() {
+        let items = stream::iter(vec![1]).chain(stream::pending());
+        let outputs: Vec<_> =
+            block_on(timeout(Duration::from_millis(20), items, 0).collect());
+
+        assert_eq!(outputs, vec![1, 0]);
+    }
+}
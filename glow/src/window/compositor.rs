@@ -78,4 +78,47 @@ impl iced_graphics::window::GLCompositor for Compositor {
             backend.present(gl, primitive, viewport, overlay);
         });
     }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8> {
+        self.present(renderer, viewport, background_color, overlay);
+
+        let size = viewport.physical_size();
+        let row_length = size.width as usize * 4;
+        let mut bytes = vec![0; row_length * size.height as usize];
+
+        unsafe {
+            self.gl.read_pixels(
+                0,
+                0,
+                size.width as i32,
+                size.height as i32,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                &mut bytes,
+            );
+        }
+
+        // OpenGL stores rows from the bottom to the top
+        bytes.chunks(row_length).rev().flatten().copied().collect()
+    }
+
+    fn fetch_information(&self) -> iced_graphics::window::Information {
+        let (adapter, version) = unsafe {
+            (
+                self.gl.get_parameter_string(glow::RENDERER),
+                self.gl.get_parameter_string(glow::VERSION),
+            )
+        };
+
+        iced_graphics::window::Information {
+            adapter,
+            backend: format!("OpenGL {}", version),
+        }
+    }
 }
@@ -5,7 +5,7 @@ use crate::{Error, Executor, Runtime};
 pub use iced_winit::Application;
 
 use iced_graphics::window;
-use iced_winit::application;
+use iced_winit::application::{self, UserEvent};
 use iced_winit::conversion;
 use iced_winit::futures;
 use iced_winit::futures::channel::mpsc;
@@ -97,6 +97,8 @@ where
         &mut clipboard,
         &mut proxy,
         context.window(),
+        &compositor.fetch_information(),
+        None,
     );
     runtime.track(subscription);
 
@@ -160,9 +162,11 @@ async fn run_instance<A, E, C>(
     mut renderer: A::Renderer,
     mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
     mut clipboard: Clipboard,
-    mut proxy: glutin::event_loop::EventLoopProxy<A::Message>,
+    mut proxy: glutin::event_loop::EventLoopProxy<UserEvent<A::Message>>,
     mut debug: Debug,
-    mut receiver: mpsc::UnboundedReceiver<glutin::event::Event<'_, A::Message>>,
+    mut receiver: mpsc::UnboundedReceiver<
+        glutin::event::Event<'_, UserEvent<A::Message>>,
+    >,
     mut context: glutin::ContextWrapper<glutin::PossiblyCurrent, Window>,
     exit_on_close_request: bool,
 ) where
@@ -184,15 +188,21 @@ async fn run_instance<A, E, C>(
             &mut debug,
         ));
 
+    let graphics_info = compositor.fetch_information();
     let mut mouse_interaction = mouse::Interaction::default();
     let mut events = Vec::new();
     let mut messages = Vec::new();
+    let mut exit_requested = false;
 
     debug.startup_finished();
 
     while let Some(event) = receiver.next().await {
         match event {
             event::Event::MainEventsCleared => {
+                if application::is_exit_ready(exit_requested, &messages) {
+                    break;
+                }
+
                 if events.is_empty() && messages.is_empty() {
                     continue;
                 }
@@ -231,12 +241,14 @@ async fn run_instance<A, E, C>(
                         &mut debug,
                         &mut messages,
                         context.window(),
+                        &graphics_info,
                     );
 
                     // Update window
                     state.synchronize(&application, context.window());
 
-                    let should_exit = application.should_exit();
+                    let should_exit =
+                        exit_requested || application.should_exit();
 
                     user_interface =
                         ManuallyDrop::new(application::build_user_interface(
@@ -277,9 +289,46 @@ async fn run_instance<A, E, C>(
                     )),
                 ));
             }
-            event::Event::UserEvent(message) => {
+            event::Event::UserEvent(UserEvent::Message(message)) => {
                 messages.push(message);
             }
+            event::Event::UserEvent(UserEvent::Continue(
+                continuation,
+                completion,
+            )) => {
+                let command = runtime.enter(continuation);
+
+                application::run_command(
+                    command,
+                    &mut runtime,
+                    &mut clipboard,
+                    &mut proxy,
+                    context.window(),
+                    &graphics_info,
+                    completion.as_ref(),
+                );
+            }
+            event::Event::UserEvent(UserEvent::Exit) => {
+                // Any events queued along with the request are still
+                // processed before exiting
+                exit_requested = true;
+            }
+            event::Event::UserEvent(UserEvent::Screenshot(deliver)) => {
+                let physical_size = state.physical_size();
+
+                let bytes = compositor.screenshot(
+                    &mut renderer,
+                    state.viewport(),
+                    state.background_color(),
+                    &debug.overlay(),
+                );
+
+                deliver(iced_native::window::Screenshot {
+                    width: physical_size.width,
+                    height: physical_size.height,
+                    bytes,
+                });
+            }
             event::Event::RedrawRequested(_) => {
                 debug.render_started();
 
@@ -363,6 +412,12 @@ async fn run_instance<A, E, C>(
                 ) {
                     events.push(event);
                 }
+
+                if let Some(event) =
+                    state.update_placement(context.window(), &window_event)
+                {
+                    events.push(crate::Event::Window(event));
+                }
             }
             _ => {}
         }
@@ -21,6 +21,7 @@ pub mod application;
 
 pub use iced_winit::clipboard;
 pub use iced_winit::settings;
+pub use iced_winit::system;
 pub use iced_winit::window;
 pub use iced_winit::{Error, Mode};
 
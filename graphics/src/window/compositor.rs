@@ -1,5 +1,6 @@
 use crate::{Color, Error, Viewport};
 
+use iced_native::futures::future::BoxFuture;
 use raw_window_handle::HasRawWindowHandle;
 use thiserror::Error;
 
@@ -49,6 +50,35 @@ pub trait Compositor: Sized {
         background_color: Color,
         overlay: &[T],
     ) -> Result<(), SurfaceError>;
+
+    /// Renders the [`Renderer`] primitives into an offscreen image and
+    /// returns a future producing its RGBA pixels, row by row.
+    ///
+    /// The image has the physical size of the given [`Viewport`]. Reading the
+    /// pixels back may take a while, so the future should be run outside of
+    /// the rendering loop.
+    ///
+    /// [`Renderer`]: Self::Renderer
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> BoxFuture<'static, Vec<u8>>;
+
+    /// Returns [`Information`] used by this [`Compositor`].
+    fn fetch_information(&self) -> Information;
+}
+
+/// Contains information about the graphics (e.g. graphics adapter, graphics
+/// backend).
+#[derive(Debug, Clone)]
+pub struct Information {
+    /// Contains the graphics adapter.
+    pub adapter: String,
+    /// Contains the graphics backend.
+    pub backend: String,
 }
 
 /// Result of an unsuccessful call to [`Compositor::draw`].
@@ -1,3 +1,4 @@
+use crate::window::Information;
 use crate::{Color, Error, Size, Viewport};
 
 use core::ffi::c_void;
@@ -59,4 +60,28 @@ pub trait GLCompositor: Sized {
         background_color: Color,
         overlay: &[T],
     );
+
+    /// Draws the primitives of the [`Renderer`] into the back buffer, like
+    /// [`GLCompositor::present`], and returns the RGBA pixels of the result,
+    /// row by row.
+    ///
+    /// The buffers are not swapped, so the result is not shown until the next
+    /// frame is drawn over it and presented.
+    ///
+    /// The image has the physical size of the given [`Viewport`].
+    ///
+    /// The pixels are read back synchronously, so this blocks until the GPU
+    /// has finished drawing.
+    ///
+    /// [`Renderer`]: crate::Renderer
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> Vec<u8>;
+
+    /// Returns [`Information`] used by this [`GLCompositor`].
+    fn fetch_information(&self) -> Information;
 }
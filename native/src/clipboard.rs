@@ -1,5 +1,6 @@
 //! Access the clipboard.
 use std::fmt;
+use std::path::PathBuf;
 
 /// A buffer for short-term storage and transfer within and between
 /// applications.
@@ -23,15 +24,84 @@ impl Clipboard for Null {
     fn write(&mut self, _contents: String) {}
 }
 
+/// An image stored in a [`Clipboard`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageData {
+    /// The width of the image, in pixels.
+    pub width: usize,
+
+    /// The height of the image, in pixels.
+    pub height: usize,
+
+    /// The RGBA pixels of the image, row by row.
+    pub bytes: Vec<u8>,
+}
+
+/// An error that occurred while writing to a [`Clipboard`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The clipboard is not available in the current platform.
+    Unsupported,
+
+    /// The clipboard backend failed with the given message.
+    Backend(String),
+
+    /// The window was not focused, which some platforms require in order to
+    /// write to the clipboard.
+    NotFocused,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsupported => write!(f, "the clipboard is not supported"),
+            Self::Backend(message) => write!(f, "{}", message),
+            Self::NotFocused => write!(f, "the window is not focused"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A callback producing some `T` with the result of a write.
+type OnWrite<T> = Box<dyn FnOnce(Result<(), Error>) -> T + Send>;
+
 /// A clipboard action to be performed by some [`Command`].
 ///
 /// [`Command`]: crate::Command
 pub enum Action<T> {
     /// Read the clipboard and produce `T` with the result.
-    Read(Box<dyn Fn(Option<String>) -> T>),
+    Read(Box<dyn Fn(Option<String>) -> T + Send>),
+
+    /// Write the given contents to the clipboard and, optionally, produce
+    /// `T` with the result.
+    Write(String, Option<OnWrite<T>>),
+
+    /// Read an image from the clipboard and produce `T` with the result.
+    ReadImage(Box<dyn Fn(Option<ImageData>) -> T + Send>),
+
+    /// Write the given image to the clipboard.
+    WriteImage(ImageData),
+
+    /// Read HTML from the clipboard and produce `T` with the result.
+    ReadHtml(Box<dyn Fn(Option<String>) -> T + Send>),
 
-    /// Write the given contents to the clipboard.
-    Write(String),
+    /// Write the given HTML to the clipboard.
+    WriteHtml {
+        /// The HTML contents
+        html: String,
+        /// The plain text alternative of the contents, if any
+        alt_text: Option<String>,
+    },
+
+    /// Read a list of files from the clipboard and produce `T` with the
+    /// result.
+    ///
+    /// The list is empty if the clipboard does not contain any files.
+    ReadFiles(Box<dyn Fn(Vec<PathBuf>) -> T + Send>),
+
+    /// Write the given list of files to the clipboard.
+    WriteFiles(Vec<PathBuf>),
 }
 
 impl<T> Action<T> {
@@ -42,7 +112,40 @@ impl<T> Action<T> {
     {
         match self {
             Self::Read(o) => Action::Read(Box::new(move |s| f(o(s)))),
-            Self::Write(content) => Action::Write(content),
+            Self::Write(content, o) => Action::Write(
+                content,
+                o.map(|o| Box::new(move |result| f(o(result))) as OnWrite<A>),
+            ),
+            Self::ReadImage(o) => {
+                Action::ReadImage(Box::new(move |image| f(o(image))))
+            }
+            Self::WriteImage(image) => Action::WriteImage(image),
+            Self::ReadHtml(o) => Action::ReadHtml(Box::new(move |s| f(o(s)))),
+            Self::WriteHtml { html, alt_text } => {
+                Action::WriteHtml { html, alt_text }
+            }
+            Self::ReadFiles(o) => {
+                Action::ReadFiles(Box::new(move |files| f(o(files))))
+            }
+            Self::WriteFiles(files) => Action::WriteFiles(files),
+        }
+    }
+
+    /// Changes the output type of a clipboard [`Action`] that produces no
+    /// outputs, or returns it unchanged otherwise.
+    pub(crate) fn without_outputs<A>(self) -> Result<Action<A>, Self> {
+        match self {
+            Self::Write(contents, None) => Ok(Action::Write(contents, None)),
+            Self::WriteImage(image) => Ok(Action::WriteImage(image)),
+            Self::WriteHtml { html, alt_text } => {
+                Ok(Action::WriteHtml { html, alt_text })
+            }
+            Self::WriteFiles(files) => Ok(Action::WriteFiles(files)),
+            Self::Read(_)
+            | Self::Write(_, Some(_))
+            | Self::ReadImage(_)
+            | Self::ReadHtml(_)
+            | Self::ReadFiles(_) => Err(self),
         }
     }
 }
@@ -51,7 +154,15 @@ impl<T> fmt::Debug for Action<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Read(_) => write!(f, "Action::Read"),
-            Self::Write(_) => write!(f, "Action::Write"),
+            Self::Write(_, _) => write!(f, "Action::Write"),
+            Self::ReadImage(_) => write!(f, "Action::ReadImage"),
+            Self::WriteImage(_) => write!(f, "Action::WriteImage"),
+            Self::ReadHtml(_) => write!(f, "Action::ReadHtml"),
+            Self::WriteHtml { .. } => write!(f, "Action::WriteHtml"),
+            Self::ReadFiles(_) => write!(f, "Action::ReadFiles"),
+            Self::WriteFiles(files) => {
+                write!(f, "Action::WriteFiles({:?})", files)
+            }
         }
     }
 }
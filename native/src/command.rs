@@ -1,10 +1,20 @@
 //! Run asynchronous actions.
+mod abort;
 mod action;
+mod chain;
+mod sequence;
 
+pub use abort::AbortHandle;
 pub use action::Action;
+pub use chain::{Chain, Continuation};
+pub use sequence::{Completion, Sequence, Step};
+
+use iced_futures::futures::Stream;
 
 use std::fmt;
 use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
 
 /// A set of asynchronous actions to be performed by some runtime.
 pub struct Command<T>(iced_futures::Command<Action<T>>);
@@ -32,16 +42,100 @@ impl<T> Command<T> {
         Command::single(Action::Future(Box::pin(future.map(f))))
     }
 
+    /// Creates a [`Command`] that performs the futures created by the given
+    /// `factory` until one of them succeeds, as defined by the given
+    /// [`RetryPolicy`].
+    ///
+    /// [`RetryPolicy`]: iced_futures::retry::RetryPolicy
+    pub fn perform_with_retry<F, V, E>(
+        factory: impl Fn() -> F + 'static + Send,
+        policy: iced_futures::retry::RetryPolicy,
+        f: impl Fn(Result<V, iced_futures::retry::Failure<E>>) -> T + 'static + Send,
+    ) -> Self
+    where
+        F: Future<Output = Result<V, E>> + 'static + Send,
+        V: Send + 'static,
+        E: Send + 'static,
+    {
+        Command::perform(iced_futures::retry::retry(factory, policy), f)
+    }
+
+    /// Creates a [`Command`] that runs the given stream to completion,
+    /// producing a result for every one of its items.
+    pub fn run<A>(
+        stream: impl Stream<Item = T> + 'static + Send,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        use iced_futures::futures::StreamExt;
+
+        Command::single(Action::Stream(Box::pin(stream.map(f))))
+    }
+
+    /// Creates a [`Command`] that produces the given value once the given
+    /// `duration` has elapsed.
+    pub fn delay(duration: Duration, value: T) -> Self
+    where
+        T: Send + 'static,
+    {
+        Self::single(Action::Future(Box::pin(async move {
+            iced_futures::time::delay(duration).await;
+
+            value
+        })))
+    }
+
+    /// Races the futures and streams of a [`Command`] against a timer of the
+    /// given `duration`.
+    ///
+    /// If the timer fires before all of them are done, `on_timeout` is
+    /// produced and any pending work is dropped. Any other actions of the
+    /// [`Command`] are run as usual.
+    ///
+    /// Commands chained with [`Command::then`] or nested with
+    /// [`Command::sequence`] are not raced either; they keep running and
+    /// producing results after the timer fires.
+    pub fn timeout(duration: Duration, command: Self, on_timeout: T) -> Self
+    where
+        T: Send + 'static,
+    {
+        let (command, outputs) = command.split();
+
+        match outputs {
+            Some(outputs) => {
+                let outputs =
+                    iced_futures::time::timeout(duration, outputs, on_timeout);
+
+                Self::batch(vec![
+                    command,
+                    Command::single(Action::Stream(Box::pin(outputs))),
+                ])
+            }
+            None => command,
+        }
+    }
+
     /// Creates a [`Command`] that performs the actions of all the given
     /// commands.
     ///
     /// Once this command is run, all the commands will be executed at once.
+    /// Their results may be produced in any order; use [`Command::sequence`]
+    /// if you need them to run one after the other.
     pub fn batch(commands: impl IntoIterator<Item = Command<T>>) -> Self {
         Self(iced_futures::Command::batch(
             commands.into_iter().map(|Command(command)| command),
         ))
     }
 
+    /// Creates a [`Command`] that performs the actions of all the given
+    /// commands in order.
+    ///
+    /// Each command is only run once all the results of the previous one have
+    /// been produced, including the results of the commands it chains with
+    /// [`Command::then`] or nests with [`Command::sequence`].
+    pub fn sequence(commands: impl IntoIterator<Item = Command<T>>) -> Self {
+        Self::single(Action::Sequence(Sequence::new(commands)))
+    }
+
     /// Applies a transformation to the result of a [`Command`].
     pub fn map<A>(
         self,
@@ -49,12 +143,125 @@ impl<T> Command<T> {
     ) -> Command<A>
     where
         T: 'static,
+        A: 'static,
     {
         let Command(command) = self;
 
         Command(command.map(move |action| action.map(f.clone())))
     }
 
+    /// Feeds the results of a [`Command`] into a continuation, running the
+    /// [`Command`] it returns.
+    ///
+    /// The results will not be sent to the application. Instead, the
+    /// continuation will be called once for every result of the [`Command`],
+    /// as soon as it is available. This allows sequencing multiple steps of a
+    /// single operation without going through `update` in between.
+    pub fn then<A>(
+        self,
+        f: impl Fn(T) -> Command<A> + 'static + Send + Sync,
+    ) -> Command<A>
+    where
+        T: Send + 'static,
+        A: 'static,
+    {
+        self.then_shared(Arc::new(f))
+    }
+
+    fn then_shared<A>(
+        self,
+        f: Arc<dyn Fn(T) -> Command<A> + Send + Sync>,
+    ) -> Command<A>
+    where
+        T: Send + 'static,
+        A: 'static,
+    {
+        let Command(command) = self;
+
+        Command(command.map(move |action| action.then(f.clone())))
+    }
+
+    /// Makes the [`Command`] abortable, returning an [`AbortHandle`] that can
+    /// be used to cancel it.
+    ///
+    /// Aborting drops any future or stream of the [`Command`] that is still
+    /// running, so its results are never produced. This includes the commands
+    /// produced later on by the continuations of [`Command::then`]. Actions
+    /// that run right away, like clipboard and window actions, are not
+    /// affected.
+    pub fn abortable(self) -> (Self, AbortHandle)
+    where
+        T: 'static,
+    {
+        let handle = AbortHandle::default();
+        let command = self.abortable_with(&handle);
+
+        (command, handle)
+    }
+
+    fn abortable_with(self, handle: &AbortHandle) -> Self
+    where
+        T: 'static,
+    {
+        Command::batch(
+            self.actions()
+                .into_iter()
+                .map(|action| Command::single(action.abortable(handle))),
+        )
+    }
+
+    /// Splits the [`Command`] into the actions that can be run right away and
+    /// a single stream with the results of all of its futures and streams.
+    fn split(self) -> (Self, Option<iced_futures::BoxStream<T>>)
+    where
+        T: 'static,
+    {
+        use iced_futures::futures::stream;
+        use iced_futures::futures::FutureExt;
+
+        let mut streams = Vec::new();
+        let mut others = Vec::new();
+
+        for action in self.actions() {
+            match action {
+                Action::Future(future) => {
+                    streams.push(Box::pin(future.into_stream()) as _)
+                }
+                Action::Stream(stream) => streams.push(stream),
+                action => others.push(Command::single(action)),
+            }
+        }
+
+        let outputs = if streams.is_empty() {
+            None
+        } else {
+            Some(Box::pin(stream::select_all(streams)) as _)
+        };
+
+        (Self::batch(others), outputs)
+    }
+
+    /// Returns the amount of actions of the [`Command`].
+    ///
+    /// Along with [`is_none`] and [`actions`], this is mostly useful to
+    /// inspect the commands produced by an application in tests.
+    ///
+    /// [`is_none`]: Self::is_none
+    /// [`actions`]: Self::actions
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        let Command(command) = self;
+
+        command.len()
+    }
+
+    /// Returns whether the [`Command`] does nothing.
+    pub fn is_none(&self) -> bool {
+        let Command(command) = self;
+
+        command.is_none()
+    }
+
     /// Returns all of the actions of the [`Command`].
     pub fn actions(self) -> Vec<Action<T>> {
         let Command(command) = self;
@@ -63,6 +270,29 @@ impl<T> Command<T> {
     }
 }
 
+impl<T, E> Command<Result<T, E>> {
+    /// Feeds the successful results of a [`Command`] into a continuation,
+    /// running the [`Command`] it returns.
+    ///
+    /// Any error is produced right away, skipping the continuation.
+    ///
+    /// See [`Command::then`] to learn more.
+    pub fn and_then<A>(
+        self,
+        f: impl Fn(T) -> Command<Result<A, E>> + 'static + Send + Sync,
+    ) -> Command<Result<A, E>>
+    where
+        T: Send + 'static,
+        E: Send + 'static,
+        A: 'static,
+    {
+        self.then(move |result| match result {
+            Ok(value) => f(value),
+            Err(error) => Command::perform(async move { error }, Err),
+        })
+    }
+}
+
 impl<T> fmt::Debug for Command<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let Command(command) = self;
@@ -70,3 +300,43 @@ impl<T> fmt::Debug for Command<T> {
         command.fmt(f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Action, Command};
+
+    use iced_futures::futures::executor::block_on;
+    use iced_futures::futures::StreamExt;
+
+    use std::time::Duration;
+
+    #[test]
+    fn timeout_does_not_race_chains() {
+        let command = Command::timeout(
+            Duration::from_secs(60),
+            Command::batch(vec![
+                Command::perform(async { 1 }, |value| value),
+                Command::perform(async { 2 }, |value| value).then(|value| {
+                    Command::perform(async move { value * 10 }, |value| value)
+                }),
+            ]),
+            0,
+        );
+
+        let mut raced = Vec::new();
+        let mut chains = 0;
+
+        for action in command.actions() {
+            match action {
+                Action::Stream(stream) => {
+                    raced.extend(block_on(stream.collect::<Vec<_>>()))
+                }
+                Action::Chain(_) => chains += 1,
+                action => panic!("Unexpected action: {:?}", action),
+            }
+        }
+
+        assert_eq!(raced, vec![1]);
+        assert_eq!(chains, 1);
+    }
+}
@@ -0,0 +1,196 @@
+use iced_futures::futures::future::{self, Abortable};
+use iced_futures::futures::StreamExt;
+use iced_futures::BoxStream;
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// A handle to abort the actions of a [`Command`].
+///
+/// You can obtain one by calling [`Command::abortable`].
+///
+/// [`Command`]: crate::Command
+/// [`Command::abortable`]: crate::Command::abortable
+#[derive(Debug, Clone, Default)]
+pub struct AbortHandle {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    is_aborted: bool,
+    next_id: u64,
+    handles: HashMap<u64, future::AbortHandle>,
+}
+
+impl AbortHandle {
+    /// Aborts the actions of the [`Command`] linked to this [`AbortHandle`].
+    ///
+    /// Their results will never be produced, and any action linked to it
+    /// afterwards is aborted right away. Aborting actions that have already
+    /// finished has no effect.
+    ///
+    /// [`Command`]: crate::Command
+    pub fn abort(&self) {
+        let mut state = self.state.lock().expect("Lock abort handle");
+
+        state.is_aborted = true;
+
+        for (_, handle) in state.handles.drain() {
+            handle.abort();
+        }
+    }
+
+    /// Links a [`Stream`] to the [`AbortHandle`], so that aborting stops it.
+    ///
+    /// The link is removed once the [`Stream`] is dropped.
+    ///
+    /// [`Stream`]: iced_futures::futures::Stream
+    pub(crate) fn link<T: 'static>(
+        &self,
+        stream: BoxStream<T>,
+    ) -> BoxStream<T> {
+        let (handle, registration) = future::AbortHandle::new_pair();
+        let mut state = self.state.lock().expect("Lock abort handle");
+
+        if state.is_aborted {
+            handle.abort();
+
+            return Box::pin(Abortable::new(stream, registration));
+        }
+
+        let id = state.next_id;
+
+        state.next_id += 1;
+        let _ = state.handles.insert(id, handle);
+
+        let link = Link {
+            id,
+            state: self.state.clone(),
+        };
+
+        Box::pin(Abortable::new(stream, registration).map(move |output| {
+            // The link lives as long as the stream
+            let _ = &link;
+
+            output
+        }))
+    }
+}
+
+/// The link between a running [`Stream`] and an [`AbortHandle`].
+///
+/// [`Stream`]: iced_futures::futures::Stream
+struct Link {
+    id: u64,
+    state: Arc<Mutex<State>>,
+}
+
+impl Drop for Link {
+    fn drop(&mut self) {
+        if let Ok(mut state) = self.state.lock() {
+            let _ = state.handles.remove(&self.id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::command::{Action, Command};
+
+    use iced_futures::futures::executor::block_on;
+    use iced_futures::futures::{stream, StreamExt};
+
+    fn run<T>(command: Command<T>) -> Vec<T> {
+        command
+            .actions()
+            .into_iter()
+            .flat_map(|action| match action {
+                Action::Future(future) => vec![block_on(future)],
+                Action::Stream(stream) => block_on(stream.collect()),
+                action => panic!("Unexpected action: {:?}", action),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn aborted_future_produces_nothing() {
+        let (command, handle) =
+            Command::perform(async { 1 }, |value| value).abortable();
+
+        handle.abort();
+
+        assert!(run(command).is_empty());
+    }
+
+    #[test]
+    fn batch_shares_handle() {
+        let (command, handle) = Command::batch(vec![
+            Command::perform(async { 1 }, |value| value),
+            Command::run(stream::iter(vec![2, 3]), |value| value),
+        ])
+        .abortable();
+
+        handle.abort();
+
+        assert!(run(command).is_empty());
+    }
+
+    #[test]
+    fn abort_reaches_continuation() {
+        let (command, handle) = Command::perform(async { 1 }, |value| value)
+            .then(|value| {
+                Command::perform(async move { value + 1 }, |value| value)
+            })
+            .abortable();
+
+        let continuations: Vec<_> = command
+            .actions()
+            .into_iter()
+            .flat_map(|action| match action {
+                Action::Chain(chain) => {
+                    run(Command::single(chain.into_action()))
+                }
+                action => panic!("Unexpected action: {:?}", action),
+            })
+            .collect();
+
+        handle.abort();
+
+        let outputs: Vec<_> = continuations
+            .into_iter()
+            .flat_map(|continuation| run(continuation()))
+            .collect();
+
+        assert!(outputs.is_empty());
+    }
+
+    #[test]
+    fn abort_after_completion_is_noop() {
+        let (command, handle) =
+            Command::perform(async { 1 }, |value| value).abortable();
+
+        let output = run(command);
+
+        handle.abort();
+
+        assert_eq!(output, vec![1]);
+    }
+
+    #[test]
+    fn finished_actions_are_unlinked() {
+        let (command, handle) =
+            Command::perform(async { 1 }, |value| value).abortable();
+
+        let _ = run(command);
+
+        for value in 0..10 {
+            let command = Command::perform(async move { value }, |value| value)
+                .abortable_with(&handle);
+
+            let _ = run(command);
+        }
+
+        assert!(handle.state.lock().unwrap().handles.is_empty());
+    }
+}
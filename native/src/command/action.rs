@@ -1,7 +1,10 @@
 use crate::clipboard;
+use crate::command::{AbortHandle, Chain, Command, Sequence};
+use crate::system;
 use crate::window;
 
 use std::fmt;
+use std::sync::Arc;
 
 /// An action that a [`Command`] can perform.
 ///
@@ -10,11 +13,25 @@ pub enum Action<T> {
     /// Run a [`Future`] to completion.
     Future(iced_futures::BoxFuture<T>),
 
+    /// Run a [`Stream`] to completion, producing an output for every item.
+    ///
+    /// [`Stream`]: iced_futures::futures::Stream
+    Stream(iced_futures::BoxStream<T>),
+
     /// Run a clipboard action.
     Clipboard(clipboard::Action<T>),
 
     /// Run a window action.
-    Window(window::Action),
+    Window(window::Action<T>),
+
+    /// Run a system action.
+    System(system::Action<T>),
+
+    /// Run an action and feed its outputs into a continuation.
+    Chain(Chain<T>),
+
+    /// Run a list of commands one after the other.
+    Sequence(Sequence<T>),
 }
 
 impl<T> Action<T> {
@@ -22,13 +39,90 @@ impl<T> Action<T> {
     pub fn map<A>(self, f: impl Fn(T) -> A + 'static + Send + Sync) -> Action<A>
     where
         T: 'static,
+        A: 'static,
     {
-        use iced_futures::futures::FutureExt;
+        use iced_futures::futures::{FutureExt, StreamExt};
 
         match self {
             Self::Future(future) => Action::Future(Box::pin(future.map(f))),
+            Self::Stream(stream) => Action::Stream(Box::pin(stream.map(f))),
             Self::Clipboard(action) => Action::Clipboard(action.map(f)),
-            Self::Window(window) => Action::Window(window),
+            Self::Window(action) => Action::Window(action.map(f)),
+            Self::System(action) => Action::System(action.map(f)),
+            Self::Chain(chain) => Action::Chain(chain.map(f)),
+            Self::Sequence(sequence) => Action::Sequence(sequence.map(f)),
+        }
+    }
+
+    /// Returns the window action of the [`Action`], if it is one.
+    pub fn as_window(&self) -> Option<&window::Action<T>> {
+        match self {
+            Self::Window(action) => Some(action),
+            _ => None,
+        }
+    }
+
+    /// Returns the clipboard action of the [`Action`], if it is one.
+    pub fn as_clipboard(&self) -> Option<&clipboard::Action<T>> {
+        match self {
+            Self::Clipboard(action) => Some(action),
+            _ => None,
+        }
+    }
+
+    /// Feeds the outputs of the [`Action`] into the given continuation.
+    pub(crate) fn then<A>(
+        self,
+        f: Arc<dyn Fn(T) -> Command<A> + Send + Sync>,
+    ) -> Action<A>
+    where
+        T: Send + 'static,
+        A: 'static,
+    {
+        match self {
+            Self::Future(_) | Self::Stream(_) | Self::System(_) => {
+                Action::Chain(Chain::new(self, f))
+            }
+            Self::Clipboard(action) => match action.without_outputs() {
+                Ok(action) => Action::Clipboard(action),
+                Err(action) => {
+                    Action::Chain(Chain::new(Self::Clipboard(action), f))
+                }
+            },
+            Self::Window(action) => match action.without_outputs() {
+                Ok(action) => Action::Window(action),
+                Err(action) => {
+                    Action::Chain(Chain::new(Self::Window(action), f))
+                }
+            },
+            Self::Chain(chain) => Action::Chain(chain.then(f)),
+            Self::Sequence(sequence) => Action::Sequence(sequence.then(f)),
+        }
+    }
+
+    /// Links the asynchronous work of the [`Action`] to the given
+    /// [`AbortHandle`].
+    ///
+    /// Any aborted [`Future`] or [`Stream`] will stop producing outputs.
+    ///
+    /// [`Future`]: std::future::Future
+    /// [`Stream`]: iced_futures::futures::Stream
+    pub(crate) fn abortable(self, handle: &AbortHandle) -> Self
+    where
+        T: 'static,
+    {
+        use iced_futures::futures::FutureExt;
+
+        match self {
+            Self::Future(future) => {
+                Self::Stream(handle.link(Box::pin(future.into_stream())))
+            }
+            Self::Stream(stream) => Self::Stream(handle.link(stream)),
+            Self::Chain(chain) => Self::Chain(chain.abortable(handle)),
+            Self::Sequence(sequence) => {
+                Self::Sequence(sequence.abortable(handle))
+            }
+            Self::Clipboard(_) | Self::Window(_) | Self::System(_) => self,
         }
     }
 }
@@ -37,10 +131,14 @@ impl<T> fmt::Debug for Action<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Future(_) => write!(f, "Action::Future"),
+            Self::Stream(_) => write!(f, "Action::Stream"),
             Self::Clipboard(action) => {
                 write!(f, "Action::Clipboard({:?})", action)
             }
             Self::Window(action) => write!(f, "Action::Window({:?})", action),
+            Self::System(action) => write!(f, "Action::System({:?})", action),
+            Self::Chain(chain) => write!(f, "Action::{:?}", chain),
+            Self::Sequence(sequence) => write!(f, "Action::{:?}", sequence),
         }
     }
 }
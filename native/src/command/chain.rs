@@ -0,0 +1,131 @@
+use crate::command::{AbortHandle, Action, Command};
+
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+/// The type-erased output of the [`Action`] of a [`Chain`].
+type Output = Box<dyn Any + Send>;
+
+/// A deferred step of a [`Chain`].
+///
+/// Calling it produces the next [`Command`] to run.
+pub type Continuation<T> = Box<dyn FnOnce() -> Command<T> + Send>;
+
+/// An [`Action`] whose outputs are fed into a continuation producing a new
+/// [`Command`], instead of being sent to the application.
+pub struct Chain<T> {
+    action: Box<Action<Output>>,
+    continuation: Arc<dyn Fn(Output) -> Command<T> + Send + Sync>,
+}
+
+impl<T> Chain<T> {
+    /// Creates a new [`Chain`] from an [`Action`] and a continuation.
+    pub(crate) fn new<A>(
+        action: Action<A>,
+        f: Arc<dyn Fn(A) -> Command<T> + Send + Sync>,
+    ) -> Self
+    where
+        T: 'static,
+        A: Send + 'static,
+    {
+        let action = action.map(|output| Box::new(output) as Output);
+
+        Self {
+            action: Box::new(action),
+            continuation: Arc::new(move |output: Output| {
+                let output = output
+                    .downcast::<A>()
+                    .expect("Downcast output of chained action");
+
+                f(*output)
+            }),
+        }
+    }
+
+    /// Applies a transformation to the result of the [`Chain`].
+    pub fn map<A>(self, f: impl Fn(T) -> A + 'static + Send + Sync) -> Chain<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        self.map_shared(Arc::new(f))
+    }
+
+    fn map_shared<A>(self, f: Arc<dyn Fn(T) -> A + Send + Sync>) -> Chain<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        let continuation = self.continuation;
+
+        Chain {
+            action: self.action,
+            continuation: Arc::new(move |output| {
+                let f = f.clone();
+
+                continuation(output).map(move |value| f(value))
+            }),
+        }
+    }
+
+    /// Feeds the results of the [`Chain`] into another continuation.
+    pub(crate) fn then<A>(
+        self,
+        f: Arc<dyn Fn(T) -> Command<A> + Send + Sync>,
+    ) -> Chain<A>
+    where
+        T: Send + 'static,
+        A: 'static,
+    {
+        let continuation = self.continuation;
+
+        Chain {
+            action: self.action,
+            continuation: Arc::new(move |output| {
+                continuation(output).then_shared(f.clone())
+            }),
+        }
+    }
+
+    /// Links the [`Action`] of the [`Chain`] and every [`Command`] produced by
+    /// its continuation to the given [`AbortHandle`].
+    pub(crate) fn abortable(self, handle: &AbortHandle) -> Self
+    where
+        T: 'static,
+    {
+        let continuation = self.continuation;
+        let handle = handle.clone();
+
+        Chain {
+            action: Box::new(self.action.abortable(&handle)),
+            continuation: Arc::new(move |output| {
+                continuation(output).abortable_with(&handle)
+            }),
+        }
+    }
+
+    /// Returns the [`Action`] of the [`Chain`], producing a [`Continuation`]
+    /// for each one of its outputs.
+    ///
+    /// Runtimes must run each [`Continuation`] once it is available and then
+    /// run the [`Command`] it produces.
+    pub fn into_action(self) -> Action<Continuation<T>>
+    where
+        T: 'static,
+    {
+        let continuation = self.continuation;
+
+        self.action.map(move |output| {
+            let continuation = continuation.clone();
+
+            Box::new(move || continuation(output)) as Continuation<T>
+        })
+    }
+}
+
+impl<T> fmt::Debug for Chain<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Chain({:?})", self.action)
+    }
+}
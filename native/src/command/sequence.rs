@@ -0,0 +1,282 @@
+use crate::command::{AbortHandle, Command};
+
+use iced_futures::futures::channel::oneshot;
+use iced_futures::BoxFuture;
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::Arc;
+
+/// A list of commands to be run one after the other.
+///
+/// Each [`Command`] is only run once all the results of the previous one
+/// have been produced.
+pub struct Sequence<T> {
+    commands: VecDeque<Command<T>>,
+}
+
+/// The next step of a [`Sequence`], ready to be run.
+pub struct Step<T> {
+    /// The [`Command`] of the step.
+    pub command: Command<T>,
+
+    /// The [`Completion`] tracking the work of the `command`.
+    pub completion: Completion,
+
+    /// A future that resolves once every clone of the `completion` has been
+    /// dropped.
+    pub completed: BoxFuture<()>,
+
+    /// The rest of the [`Sequence`], which must only be run once the step
+    /// has been `completed`.
+    pub rest: Sequence<T>,
+}
+
+/// Tracks the work of a [`Step`] that is still running.
+///
+/// Runtimes must keep a clone of the [`Completion`] alongside any work of the
+/// [`Step`] that finishes later on, like futures, streams, and the commands
+/// produced by chained continuations or nested sequences. Each clone must
+/// only be dropped once the results of its work have been produced.
+#[derive(Debug, Clone)]
+pub struct Completion {
+    _sender: Arc<oneshot::Sender<()>>,
+}
+
+impl<T> Sequence<T> {
+    /// Creates a new [`Sequence`] from a list of commands.
+    pub(crate) fn new(commands: impl IntoIterator<Item = Command<T>>) -> Self {
+        Self {
+            commands: commands.into_iter().collect(),
+        }
+    }
+
+    /// Applies a transformation to the results of the [`Sequence`].
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + 'static + Send + Sync,
+    ) -> Sequence<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        self.map_shared(Arc::new(f))
+    }
+
+    fn map_shared<A>(self, f: Arc<dyn Fn(T) -> A + Send + Sync>) -> Sequence<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        Sequence {
+            commands: self
+                .commands
+                .into_iter()
+                .map(|command| {
+                    let f = f.clone();
+
+                    command.map(move |value| f(value))
+                })
+                .collect(),
+        }
+    }
+
+    /// Feeds the results of the [`Sequence`] into another continuation.
+    pub(crate) fn then<A>(
+        self,
+        f: Arc<dyn Fn(T) -> Command<A> + Send + Sync>,
+    ) -> Sequence<A>
+    where
+        T: Send + 'static,
+        A: 'static,
+    {
+        Sequence {
+            commands: self
+                .commands
+                .into_iter()
+                .map(|command| command.then_shared(f.clone()))
+                .collect(),
+        }
+    }
+
+    /// Links every [`Command`] of the [`Sequence`] to the given
+    /// [`AbortHandle`].
+    pub(crate) fn abortable(self, handle: &AbortHandle) -> Self
+    where
+        T: 'static,
+    {
+        Sequence {
+            commands: self
+                .commands
+                .into_iter()
+                .map(|command| command.abortable_with(handle))
+                .collect(),
+        }
+    }
+
+    /// Returns the next [`Step`] of the [`Sequence`], if any.
+    pub fn next_step(mut self) -> Option<Step<T>> {
+        use iced_futures::futures::FutureExt;
+
+        let command = self.commands.pop_front()?;
+        let (sender, receiver) = oneshot::channel();
+
+        Some(Step {
+            command,
+            completion: Completion {
+                _sender: Arc::new(sender),
+            },
+            completed: Box::pin(receiver.map(|_| ())),
+            rest: self,
+        })
+    }
+}
+
+impl<T> fmt::Debug for Sequence<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Sequence({:?})", self.commands)
+    }
+}
+
+impl<T> fmt::Debug for Step<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Step({:?}, {:?})", self.command, self.rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::command::{Action, Command, Completion};
+
+    use iced_futures::futures::channel::mpsc;
+    use iced_futures::futures::executor::{LocalPool, LocalSpawner};
+    use iced_futures::futures::task::LocalSpawnExt;
+    use iced_futures::futures::{future, stream, StreamExt};
+    use std::time::Duration;
+
+    fn after(millis: u64, value: u32) -> Command<u32> {
+        Command::delay(Duration::from_millis(millis), value)
+    }
+
+    fn run(command: Command<u32>) -> Vec<u32> {
+        let mut pool = LocalPool::new();
+        let (sender, receiver) = mpsc::unbounded();
+
+        spawn(command, &pool.spawner(), &sender, None);
+        drop(sender);
+
+        pool.run_until(receiver.collect())
+    }
+
+    /// Runs the actions of a [`Command`] concurrently, like a runtime would,
+    /// only waiting for a step of a sequence to complete before running the
+    /// next one.
+    fn spawn(
+        command: Command<u32>,
+        pool: &LocalSpawner,
+        sender: &mpsc::UnboundedSender<u32>,
+        completion: Option<&Completion>,
+    ) {
+        for action in command.actions() {
+            let spawner = pool.clone();
+            let sender = sender.clone();
+            let completion = completion.cloned();
+
+            let task = match action {
+                Action::Future(future) => Box::pin(async move {
+                    let _ = sender.unbounded_send(future.await);
+
+                    drop(completion);
+                })
+                    as future::LocalBoxFuture<'_, ()>,
+                Action::Stream(stream) => Box::pin(async move {
+                    stream
+                        .for_each(|value| {
+                            let _ = sender.unbounded_send(value);
+
+                            future::ready(())
+                        })
+                        .await;
+
+                    drop(completion);
+                }),
+                Action::Chain(chain) => match chain.into_action() {
+                    Action::Future(future) => Box::pin(async move {
+                        let continuation = future.await;
+
+                        spawn(
+                            continuation(),
+                            &spawner,
+                            &sender,
+                            completion.as_ref(),
+                        );
+                    }),
+                    action => panic!("Unexpected action: {:?}", action),
+                },
+                Action::Sequence(sequence) => {
+                    let step = match sequence.next_step() {
+                        Some(step) => step,
+                        None => continue,
+                    };
+
+                    spawn(
+                        step.command,
+                        &spawner,
+                        &sender,
+                        Some(&step.completion),
+                    );
+
+                    let completed = step.completed;
+                    let rest = Command::single(Action::Sequence(step.rest));
+
+                    Box::pin(async move {
+                        completed.await;
+
+                        spawn(rest, &spawner, &sender, completion.as_ref());
+                    })
+                }
+                action => panic!("Unexpected action: {:?}", action),
+            };
+
+            pool.spawn_local(task).expect("Spawn task");
+        }
+    }
+
+    #[test]
+    fn batch_is_unordered() {
+        let command = Command::batch(vec![after(100, 1), after(0, 2)]);
+
+        assert_eq!(run(command), vec![2, 1]);
+    }
+
+    #[test]
+    fn sequence_is_ordered() {
+        let command = Command::sequence(vec![after(100, 1), after(0, 2)]);
+
+        assert_eq!(run(command), vec![1, 2]);
+    }
+
+    #[test]
+    fn sequence_waits_for_every_result() {
+        let command = Command::sequence(vec![
+            Command::batch(vec![
+                after(100, 1),
+                Command::run(stream::iter(vec![2, 3]), |value| value),
+            ]),
+            after(0, 4),
+        ]);
+
+        assert_eq!(run(command), vec![2, 3, 1, 4]);
+    }
+
+    #[test]
+    fn sequence_waits_for_nested_sequences_and_chains() {
+        let command = Command::sequence(vec![
+            Command::sequence(vec![after(100, 1), after(0, 2)]),
+            after(50, 3).then(|value| after(50, value * 10)),
+            after(0, 4),
+        ]);
+
+        assert_eq!(run(command), vec![1, 2, 30, 4]);
+    }
+}
@@ -48,6 +48,7 @@ pub mod program;
 pub mod renderer;
 pub mod subscription;
 pub mod svg;
+pub mod system;
 pub mod text;
 pub mod touch;
 pub mod user_interface;
@@ -0,0 +1,6 @@
+//! Access the native system.
+mod action;
+mod information;
+
+pub use action::Action;
+pub use information::Information;
@@ -0,0 +1,26 @@
+/// Contains information about the system (e.g. operating system, processor,
+/// memory and graphics adapter).
+#[derive(Debug, Clone)]
+pub struct Information {
+    /// The name of the operating system
+    pub system_name: Option<String>,
+    /// The kernel version of the operating system
+    pub system_kernel: Option<String>,
+    /// The long version of the operating system
+    ///
+    /// Examples:
+    /// - MacOS 10.15 Catalina
+    /// - Windows 10 Pro
+    /// - Ubuntu 20.04 LTS (Focal Fossa)
+    pub system_version: Option<String>,
+    /// The brand of the processor
+    pub cpu_brand: String,
+    /// The amount of physical cores of the processor
+    pub cpu_cores: Option<usize>,
+    /// The total amount of memory, in KB
+    pub memory_total: u64,
+    /// The graphics backend used by the renderer
+    pub graphics_backend: String,
+    /// The name of the graphics adapter used by the renderer
+    pub graphics_adapter: String,
+}
@@ -1,6 +1,17 @@
 //! Build window-based GUI applications.
 mod action;
 mod event;
+mod monitor;
+mod screenshot;
+mod state;
+mod user_attention;
+
+pub mod icon;
 
 pub use action::Action;
 pub use event::Event;
+pub use icon::Icon;
+pub use monitor::Monitor;
+pub use screenshot::Screenshot;
+pub use state::State;
+pub use user_attention::UserAttention;
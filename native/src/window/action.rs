@@ -1,6 +1,9 @@
+use crate::window::{Icon, Monitor, Screenshot, State, UserAttention};
+
+use std::fmt;
+
 /// An operation to be performed on some window.
-#[derive(Debug)]
-pub enum Action {
+pub enum Action<T> {
     /// Resize the window.
     Resize {
         /// The new logical width of the window
@@ -15,4 +18,187 @@ pub enum Action {
         /// The new logical y location of the window
         y: i32,
     },
+    /// Move the window to the given physical coordinates.
+    SetPosition {
+        /// The new physical x location of the window
+        x: i32,
+        /// The new physical y location of the window
+        y: i32,
+    },
+    /// Center the window on a monitor.
+    ///
+    /// The monitor is an index into the list produced by
+    /// [`Action::FetchMonitors`]. If `None`, the current monitor of the
+    /// window is used.
+    Center {
+        /// The index of the monitor to center the window on
+        monitor: Option<usize>,
+    },
+    /// Close the window and exit the application.
+    Close,
+    /// Set the title of the window.
+    SetTitle(String),
+    /// Minimize the window.
+    Minimize,
+    /// Maximize or restore the window.
+    ///
+    /// This is ignored while the window is fullscreen.
+    Maximize(bool),
+    /// Maximize the window if it is not maximized, or restore it otherwise.
+    ///
+    /// This is ignored while the window is fullscreen.
+    ToggleMaximize,
+    /// Start dragging the window with the left mouse button until it is
+    /// released.
+    ///
+    /// This only has an effect right after the left mouse button is pressed.
+    Drag,
+    /// Set the icon of the window.
+    ///
+    /// This has no effect on macOS, where windows do not have icons.
+    SetIcon(Icon),
+    /// Set whether the window should always be on top of other windows.
+    SetAlwaysOnTop(bool),
+    /// Set the minimum logical size of the window, or remove the constraint
+    /// if `None`.
+    SetMinSize(Option<(u32, u32)>),
+    /// Set the maximum logical size of the window, or remove the constraint
+    /// if `None`.
+    SetMaxSize(Option<(u32, u32)>),
+    /// Set whether the window can be resized by the user.
+    SetResizable(bool),
+    /// Request the attention of the user, or clear a pending request if
+    /// `None`.
+    ///
+    /// The request is cleared automatically once the window is focused.
+    RequestUserAttention(Option<UserAttention>),
+    /// Fetch the current [`State`] of the window and produce `T` with it.
+    FetchState(Box<dyn FnOnce(State) -> T + Send>),
+    /// Fetch the monitors connected to the system and produce `T` with them.
+    FetchMonitors(Box<dyn FnOnce(Vec<Monitor>) -> T + Send>),
+    /// Render the window again into a [`Screenshot`] and produce `T` with
+    /// it.
+    Screenshot(Box<dyn FnOnce(Screenshot) -> T + Send>),
+}
+
+impl<T> Action<T> {
+    /// Maps the output of a window [`Action`] using the provided closure.
+    pub fn map<A>(self, f: impl Fn(T) -> A + 'static + Send + Sync) -> Action<A>
+    where
+        T: 'static,
+    {
+        match self {
+            Self::Resize { width, height } => Action::Resize { width, height },
+            Self::Move { x, y } => Action::Move { x, y },
+            Self::SetPosition { x, y } => Action::SetPosition { x, y },
+            Self::Center { monitor } => Action::Center { monitor },
+            Self::Close => Action::Close,
+            Self::SetTitle(title) => Action::SetTitle(title),
+            Self::Minimize => Action::Minimize,
+            Self::Maximize(maximized) => Action::Maximize(maximized),
+            Self::ToggleMaximize => Action::ToggleMaximize,
+            Self::Drag => Action::Drag,
+            Self::SetIcon(icon) => Action::SetIcon(icon),
+            Self::SetAlwaysOnTop(always_on_top) => {
+                Action::SetAlwaysOnTop(always_on_top)
+            }
+            Self::SetMinSize(size) => Action::SetMinSize(size),
+            Self::SetMaxSize(size) => Action::SetMaxSize(size),
+            Self::SetResizable(resizable) => Action::SetResizable(resizable),
+            Self::RequestUserAttention(attention) => {
+                Action::RequestUserAttention(attention)
+            }
+            Self::FetchState(o) => {
+                Action::FetchState(Box::new(move |state| f(o(state))))
+            }
+            Self::FetchMonitors(o) => {
+                Action::FetchMonitors(Box::new(move |monitors| f(o(monitors))))
+            }
+            Self::Screenshot(o) => {
+                Action::Screenshot(Box::new(move |screenshot| f(o(screenshot))))
+            }
+        }
+    }
+
+    /// Changes the output type of a window [`Action`] that produces no
+    /// outputs, or returns it unchanged otherwise.
+    pub(crate) fn without_outputs<A>(self) -> Result<Action<A>, Self> {
+        match self {
+            Self::Resize { width, height } => {
+                Ok(Action::Resize { width, height })
+            }
+            Self::Move { x, y } => Ok(Action::Move { x, y }),
+            Self::SetPosition { x, y } => Ok(Action::SetPosition { x, y }),
+            Self::Center { monitor } => Ok(Action::Center { monitor }),
+            Self::Close => Ok(Action::Close),
+            Self::SetTitle(title) => Ok(Action::SetTitle(title)),
+            Self::Minimize => Ok(Action::Minimize),
+            Self::Maximize(maximized) => Ok(Action::Maximize(maximized)),
+            Self::ToggleMaximize => Ok(Action::ToggleMaximize),
+            Self::Drag => Ok(Action::Drag),
+            Self::SetIcon(icon) => Ok(Action::SetIcon(icon)),
+            Self::SetAlwaysOnTop(always_on_top) => {
+                Ok(Action::SetAlwaysOnTop(always_on_top))
+            }
+            Self::SetMinSize(size) => Ok(Action::SetMinSize(size)),
+            Self::SetMaxSize(size) => Ok(Action::SetMaxSize(size)),
+            Self::SetResizable(resizable) => {
+                Ok(Action::SetResizable(resizable))
+            }
+            Self::RequestUserAttention(attention) => {
+                Ok(Action::RequestUserAttention(attention))
+            }
+            Self::FetchState(_)
+            | Self::FetchMonitors(_)
+            | Self::Screenshot(_) => Err(self),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Resize { width, height } => write!(
+                f,
+                "Action::Resize {{ width: {}, height: {} }}",
+                width, height
+            ),
+            Self::Move { x, y } => {
+                write!(f, "Action::Move {{ x: {}, y: {} }}", x, y)
+            }
+            Self::SetPosition { x, y } => {
+                write!(f, "Action::SetPosition {{ x: {}, y: {} }}", x, y)
+            }
+            Self::Center { monitor } => {
+                write!(f, "Action::Center {{ monitor: {:?} }}", monitor)
+            }
+            Self::Close => write!(f, "Action::Close"),
+            Self::SetTitle(title) => write!(f, "Action::SetTitle({:?})", title),
+            Self::Minimize => write!(f, "Action::Minimize"),
+            Self::Maximize(maximized) => {
+                write!(f, "Action::Maximize({})", maximized)
+            }
+            Self::ToggleMaximize => write!(f, "Action::ToggleMaximize"),
+            Self::Drag => write!(f, "Action::Drag"),
+            Self::SetIcon(icon) => write!(f, "Action::SetIcon({:?})", icon),
+            Self::SetAlwaysOnTop(always_on_top) => {
+                write!(f, "Action::SetAlwaysOnTop({})", always_on_top)
+            }
+            Self::SetMinSize(size) => {
+                write!(f, "Action::SetMinSize({:?})", size)
+            }
+            Self::SetMaxSize(size) => {
+                write!(f, "Action::SetMaxSize({:?})", size)
+            }
+            Self::SetResizable(resizable) => {
+                write!(f, "Action::SetResizable({})", resizable)
+            }
+            Self::RequestUserAttention(attention) => {
+                write!(f, "Action::RequestUserAttention({:?})", attention)
+            }
+            Self::FetchState(_) => write!(f, "Action::FetchState"),
+            Self::FetchMonitors(_) => write!(f, "Action::FetchMonitors"),
+            Self::Screenshot(_) => write!(f, "Action::Screenshot"),
+        }
+    }
 }
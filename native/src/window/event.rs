@@ -25,6 +25,22 @@ pub enum Event {
     /// occurs.
     CloseRequested,
 
+    /// A window was maximized.
+    Maximized,
+
+    /// A window was minimized.
+    ///
+    /// Minimization is detected by the window being resized to zero, which
+    /// currently only happens on Windows. Other platforms, like macOS, X11,
+    /// and Wayland, never produce this event.
+    Minimized,
+
+    /// A window was restored from being maximized or minimized.
+    ///
+    /// Since [`Event::Minimized`] is only produced on Windows, restoring a
+    /// minimized window is only reported there as well.
+    Restored,
+
     /// A window was focused.
     Focused,
 
@@ -0,0 +1,134 @@
+//! Change the icon of a window.
+use std::fmt;
+
+/// The icon of a window.
+#[derive(Clone)]
+pub struct Icon {
+    rgba: Vec<u8>,
+    width: u32,
+    height: u32,
+}
+
+impl Icon {
+    /// Creates an [`Icon`] from 32bpp RGBA data.
+    pub fn from_rgba(
+        rgba: Vec<u8>,
+        width: u32,
+        height: u32,
+    ) -> Result<Self, Error> {
+        const PIXEL_SIZE: usize = 4;
+
+        let pixel_count = rgba.len() / PIXEL_SIZE;
+
+        if pixel_count * PIXEL_SIZE != rgba.len() {
+            return Err(Error::InvalidData {
+                byte_count: rgba.len(),
+            });
+        }
+
+        if (width as usize).checked_mul(height as usize) != Some(pixel_count) {
+            return Err(Error::DimensionsMismatch {
+                width,
+                height,
+                pixel_count,
+            });
+        }
+
+        Ok(Icon {
+            rgba,
+            width,
+            height,
+        })
+    }
+
+    /// Returns the 32bpp RGBA data, the width, and the height of the
+    /// [`Icon`].
+    pub fn into_raw(self) -> (Vec<u8>, u32, u32) {
+        (self.rgba, self.width, self.height)
+    }
+}
+
+impl fmt::Debug for Icon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Icon")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish()
+    }
+}
+
+/// Creates an [`Icon`] from 32bpp RGBA data.
+pub fn from_rgba(
+    rgba: Vec<u8>,
+    width: u32,
+    height: u32,
+) -> Result<Icon, Error> {
+    Icon::from_rgba(rgba, width, height)
+}
+
+/// An error produced when using [`Icon::from_rgba`] with invalid arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The provided RGBA data isn't divisble by 4.
+    ///
+    /// Therefore, it cannot be safely interpreted as 32bpp RGBA pixels.
+    InvalidData {
+        /// The length of the provided RGBA data.
+        byte_count: usize,
+    },
+
+    /// The number of RGBA pixels does not match the provided dimensions.
+    DimensionsMismatch {
+        /// The provided width.
+        width: u32,
+        /// The provided height.
+        height: u32,
+        /// The amount of pixels of the provided RGBA data.
+        pixel_count: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidData { byte_count } => write!(
+                f,
+                "The provided RGBA data (with length {:?}) isn't divisble by \
+                4. Therefore, it cannot be safely interpreted as 32bpp RGBA \
+                pixels.",
+                byte_count,
+            ),
+            Error::DimensionsMismatch {
+                width,
+                height,
+                pixel_count,
+            } => write!(
+                f,
+                "The number of RGBA pixels ({:?}) does not match the provided \
+                dimensions ({:?}x{:?}).",
+                pixel_count, width, height,
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, Icon};
+
+    #[test]
+    fn huge_dimensions_are_rejected() {
+        let result = Icon::from_rgba(vec![0; 4], u32::MAX, u32::MAX);
+
+        assert_eq!(
+            result.map(|_| ()),
+            Err(Error::DimensionsMismatch {
+                width: u32::MAX,
+                height: u32::MAX,
+                pixel_count: 1,
+            })
+        );
+    }
+}
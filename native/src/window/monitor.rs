@@ -0,0 +1,24 @@
+/// A monitor connected to the system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    /// The name of the monitor, if available.
+    pub name: Option<String>,
+
+    /// The physical x location of the top-left corner of the monitor.
+    pub x: i32,
+
+    /// The physical y location of the top-left corner of the monitor.
+    pub y: i32,
+
+    /// The physical width of the monitor, in pixels.
+    pub width: u32,
+
+    /// The physical height of the monitor, in pixels.
+    pub height: u32,
+
+    /// The scale factor of the monitor.
+    pub scale_factor: f64,
+
+    /// Whether the monitor is the primary monitor of the system.
+    pub is_primary: bool,
+}
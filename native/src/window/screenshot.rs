@@ -0,0 +1,23 @@
+use std::fmt;
+
+/// A screenshot of the contents of a window.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Screenshot {
+    /// The physical width of the screenshot, in pixels.
+    pub width: u32,
+
+    /// The physical height of the screenshot, in pixels.
+    pub height: u32,
+
+    /// The RGBA pixels of the screenshot, row by row.
+    pub bytes: Vec<u8>,
+}
+
+impl fmt::Debug for Screenshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Screenshot")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish()
+    }
+}
@@ -0,0 +1,33 @@
+use crate::window::Monitor;
+
+/// The current state of a window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct State {
+    /// The logical width of the window.
+    pub width: u32,
+
+    /// The logical height of the window.
+    pub height: u32,
+
+    /// The logical position of the window, if the platform supports it.
+    pub position: Option<(i32, i32)>,
+
+    /// The scale factor of the window.
+    pub scale_factor: f64,
+
+    /// Whether the window is maximized.
+    pub is_maximized: bool,
+
+    /// Whether the window is minimized.
+    ///
+    /// Minimization is detected by the window having a size of zero, which
+    /// currently only happens on Windows. On other platforms, like macOS,
+    /// X11, and Wayland, this is always `false`.
+    pub is_minimized: bool,
+
+    /// Whether the window is fullscreen.
+    pub is_fullscreen: bool,
+
+    /// The monitor the window is currently on, if known.
+    pub monitor: Option<Monitor>,
+}
@@ -0,0 +1,17 @@
+/// The type of user attention to request.
+///
+/// On X11, both types set the urgency hint of the window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserAttention {
+    /// Bounces the dock icon until the application is focused on macOS.
+    ///
+    /// Flashes both the window and the taskbar button until the application
+    /// is focused on Windows.
+    Critical,
+
+    /// Bounces the dock icon once on macOS.
+    ///
+    /// Flashes the taskbar button until the application is focused on
+    /// Windows.
+    Informational,
+}
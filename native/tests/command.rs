@@ -0,0 +1,114 @@
+use iced_native::command::{self, Command};
+use iced_native::{clipboard, window};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    Reset,
+    Grow,
+    Paste,
+    Pasted(Option<String>),
+    Refresh,
+}
+
+#[derive(Debug, Default)]
+struct Editor {
+    contents: String,
+    size: u32,
+}
+
+impl Editor {
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Reset => {
+                self.contents.clear();
+
+                Command::none()
+            }
+            Message::Grow => {
+                self.size += 100;
+
+                Command::single(command::Action::Window(
+                    window::Action::Resize {
+                        width: self.size,
+                        height: self.size,
+                    },
+                ))
+            }
+            Message::Paste => Command::single(command::Action::Clipboard(
+                clipboard::Action::Read(Box::new(Message::Pasted)),
+            )),
+            Message::Pasted(contents) => {
+                self.contents.push_str(&contents.unwrap_or_default());
+
+                Command::none()
+            }
+            Message::Refresh => Command::batch(vec![
+                Command::perform(async {}, |_| Message::Reset),
+                Command::single(command::Action::Clipboard(
+                    clipboard::Action::Write(self.contents.clone(), None),
+                )),
+            ]),
+        }
+    }
+}
+
+#[test]
+fn update_without_side_effects_returns_none() {
+    let mut editor = Editor::default();
+
+    let command = editor.update(Message::Reset);
+
+    assert!(command.is_none());
+    assert_eq!(command.len(), 0);
+    assert!(command.actions().is_empty());
+}
+
+#[test]
+fn update_produces_window_action() {
+    let mut editor = Editor::default();
+
+    let command = editor.update(Message::Grow);
+    assert_eq!(command.len(), 1);
+
+    let actions = command.actions();
+
+    assert!(matches!(
+        actions[0].as_window(),
+        Some(window::Action::Resize {
+            width: 100,
+            height: 100
+        })
+    ));
+    assert!(actions[0].as_clipboard().is_none());
+}
+
+#[test]
+fn update_produces_clipboard_read() {
+    let mut editor = Editor::default();
+
+    let actions = editor.update(Message::Paste).actions();
+
+    match actions[0].as_clipboard() {
+        Some(clipboard::Action::Read(tag)) => {
+            let message = tag(Some(String::from("Hello")));
+
+            assert_eq!(message, Message::Pasted(Some(String::from("Hello"))));
+
+            let _ = editor.update(message);
+        }
+        action => panic!("Unexpected clipboard action: {:?}", action),
+    }
+
+    assert_eq!(editor.contents, "Hello");
+}
+
+#[test]
+fn update_batches_actions() {
+    let mut editor = Editor::default();
+
+    let command = editor.update(Message::Refresh);
+
+    assert!(!command.is_none());
+    assert_eq!(command.len(), 2);
+    assert!(command.actions()[1].as_clipboard().is_some());
+}
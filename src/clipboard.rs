@@ -1,3 +1,6 @@
 //! Access the clipboard.
 #[cfg(not(target_arch = "wasm32"))]
-pub use crate::runtime::clipboard::{read, write};
+pub use crate::runtime::clipboard::{
+    read, read_files, read_html, read_image, write, write_files, write_html,
+    write_image, write_with_result, Error, ImageData,
+};
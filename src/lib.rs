@@ -248,3 +248,7 @@ pub use runtime::{
     Alignment, Background, Color, Command, Font, Length, Point, Rectangle,
     Size, Subscription, Vector,
 };
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "system"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "system")))]
+pub use runtime::system;
@@ -11,4 +11,9 @@ pub use position::Position;
 pub use settings::Settings;
 
 #[cfg(not(target_arch = "wasm32"))]
-pub use crate::runtime::window::{move_to, resize};
+pub use crate::runtime::window::{
+    center, close, drag, fetch_monitors, fetch_state, maximize, minimize,
+    move_to, request_attention, resize, screenshot, set_always_on_top,
+    set_icon, set_max_size, set_min_size, set_position, set_resizable,
+    set_title, toggle_maximize, Monitor, Screenshot, State, UserAttention,
+};
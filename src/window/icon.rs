@@ -5,7 +5,7 @@ use std::io;
 /// The icon of a window.
 #[cfg(not(target_arch = "wasm32"))]
 #[derive(Debug, Clone)]
-pub struct Icon(iced_winit::winit::window::Icon);
+pub struct Icon(iced_winit::window::Icon);
 
 /// The icon of a window.
 #[cfg(target_arch = "wasm32")]
@@ -20,10 +20,9 @@ impl Icon {
         width: u32,
         height: u32,
     ) -> Result<Self, Error> {
-        let raw =
-            iced_winit::winit::window::Icon::from_rgba(rgba, width, height)?;
+        let icon = iced_winit::window::Icon::from_rgba(rgba, width, height)?;
 
-        Ok(Icon(raw))
+        Ok(Icon(icon))
     }
 
     /// Creates an icon from 32bpp RGBA data.
@@ -35,6 +34,32 @@ impl Icon {
     ) -> Result<Self, Error> {
         Ok(Icon)
     }
+
+    /// Creates an icon from the contents of an image file, like a PNG or an
+    /// ICO file.
+    #[cfg(feature = "image")]
+    pub fn from_file_data(data: &[u8]) -> Result<Self, Error> {
+        let image = image_rs::load_from_memory(data)?.into_rgba8();
+        let (width, height) = image.dimensions();
+
+        Self::from_rgba(image.into_raw(), width, height)
+    }
+}
+
+/// Creates an icon from 32bpp RGBA data.
+pub fn from_rgba(
+    rgba: Vec<u8>,
+    width: u32,
+    height: u32,
+) -> Result<Icon, Error> {
+    Icon::from_rgba(rgba, width, height)
+}
+
+/// Creates an icon from the contents of an image file, like a PNG or an ICO
+/// file.
+#[cfg(feature = "image")]
+pub fn from_file_data(data: &[u8]) -> Result<Icon, Error> {
+    Icon::from_file_data(data)
 }
 
 /// An error produced when using `Icon::from_rgba` with invalid arguments.
@@ -59,7 +84,43 @@ pub enum Error {
     },
 
     /// The underlying OS failed to create the icon.
+    ///
+    /// Icons are only created by the OS once they are applied to a window,
+    /// which logs any failure instead.
     OsError(io::Error),
+
+    /// The image file could not be decoded.
+    #[cfg(feature = "image")]
+    ImageError(image_rs::ImageError),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<iced_winit::window::icon::Error> for Error {
+    fn from(error: iced_winit::window::icon::Error) -> Self {
+        use iced_winit::window::icon;
+
+        match error {
+            icon::Error::InvalidData { byte_count } => {
+                Error::InvalidData { byte_count }
+            }
+            icon::Error::DimensionsMismatch {
+                width,
+                height,
+                pixel_count,
+            } => Error::DimensionsMismatch {
+                width,
+                height,
+                pixel_count,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "image")]
+impl From<image_rs::ImageError> for Error {
+    fn from(error: image_rs::ImageError) -> Self {
+        Error::ImageError(error)
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -87,7 +148,7 @@ impl From<iced_winit::winit::window::BadIcon> for Error {
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-impl From<Icon> for iced_winit::winit::window::Icon {
+impl From<Icon> for iced_winit::window::Icon {
     fn from(icon: Icon) -> Self {
         icon.0
     }
@@ -123,6 +184,10 @@ impl fmt::Display for Error {
                 icon: {:?}",
                 e
             ),
+            #[cfg(feature = "image")]
+            Error::ImageError(e) => {
+                write!(f, "The image file could not be decoded: {}", e)
+            }
         }
     }
 }
@@ -59,7 +59,9 @@ impl From<Settings> for iced_winit::settings::Window {
             decorations: settings.decorations,
             transparent: settings.transparent,
             always_on_top: settings.always_on_top,
-            icon: settings.icon.map(Icon::into),
+            icon: settings
+                .icon
+                .and_then(|icon| iced_winit::conversion::icon(icon.into())),
             platform_specific: Default::default(),
         }
     }
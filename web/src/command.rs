@@ -4,8 +4,12 @@ pub use action::Action;
 
 use std::fmt;
 
+#[cfg(target_arch = "wasm32")]
+use iced_futures::futures::Stream;
 #[cfg(target_arch = "wasm32")]
 use std::future::Future;
+#[cfg(target_arch = "wasm32")]
+use std::time::Duration;
 
 /// A set of asynchronous actions to be performed by some runtime.
 pub struct Command<T>(iced_futures::Command<Action<T>>);
@@ -34,16 +38,133 @@ impl<T> Command<T> {
         Command::single(Action::Future(Box::pin(future.map(f))))
     }
 
+    /// Creates a [`Command`] that performs the futures created by the given
+    /// `factory` until one of them succeeds, as defined by the given
+    /// [`RetryPolicy`].
+    ///
+    /// [`RetryPolicy`]: iced_futures::retry::RetryPolicy
+    #[cfg(target_arch = "wasm32")]
+    pub fn perform_with_retry<F, V, E>(
+        factory: impl Fn() -> F + 'static,
+        policy: iced_futures::retry::RetryPolicy,
+        f: impl Fn(Result<V, iced_futures::retry::Failure<E>>) -> T + 'static + Send,
+    ) -> Self
+    where
+        F: Future<Output = Result<V, E>> + 'static,
+        V: 'static,
+        E: 'static,
+    {
+        Command::perform(iced_futures::retry::retry(factory, policy), f)
+    }
+
+    /// Creates a [`Command`] that runs the given stream to completion,
+    /// producing a result for every one of its items.
+    #[cfg(target_arch = "wasm32")]
+    pub fn run<A>(
+        stream: impl Stream<Item = T> + 'static,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        use iced_futures::futures::StreamExt;
+
+        Command::single(Action::Stream(Box::pin(stream.map(f))))
+    }
+
+    /// Creates a [`Command`] that produces the given value once the given
+    /// `duration` has elapsed.
+    #[cfg(target_arch = "wasm32")]
+    pub fn delay(duration: Duration, value: T) -> Self
+    where
+        T: 'static,
+    {
+        Self::single(Action::Future(Box::pin(async move {
+            iced_futures::time::delay(duration).await;
+
+            value
+        })))
+    }
+
+    /// Races the futures and streams of a [`Command`] against a timer of the
+    /// given `duration`.
+    ///
+    /// If the timer fires before all of them are done, `on_timeout` is
+    /// produced and any pending work is dropped. Any other actions of the
+    /// [`Command`] are run as usual.
+    #[cfg(target_arch = "wasm32")]
+    pub fn timeout(duration: Duration, command: Self, on_timeout: T) -> Self
+    where
+        T: 'static,
+    {
+        use iced_futures::futures::stream;
+        use iced_futures::futures::FutureExt;
+
+        let mut streams = Vec::new();
+        let mut others = Vec::new();
+
+        for action in command.actions() {
+            match action {
+                Action::Future(future) => {
+                    streams.push(Box::pin(future.into_stream()) as _)
+                }
+                Action::Stream(stream) => streams.push(stream),
+                action => others.push(Command::single(action)),
+            }
+        }
+
+        if !streams.is_empty() {
+            let stream = iced_futures::time::timeout(
+                duration,
+                stream::select_all(streams),
+                on_timeout,
+            );
+
+            others.push(Command::single(Action::Stream(Box::pin(stream))));
+        }
+
+        Self::batch(others)
+    }
+
     /// Creates a [`Command`] that performs the actions of all the given
     /// commands.
     ///
     /// Once this command is run, all the commands will be executed at once.
+    /// Their results may be produced in any order; use [`Command::sequence`]
+    /// if you need them to run one after the other.
     pub fn batch(commands: impl IntoIterator<Item = Command<T>>) -> Self {
         Self(iced_futures::Command::batch(
             commands.into_iter().map(|Command(command)| command),
         ))
     }
 
+    /// Creates a [`Command`] that performs the actions of all the given
+    /// commands in order.
+    ///
+    /// Each command is only run once all the results of the previous one have
+    /// been produced.
+    #[cfg(target_arch = "wasm32")]
+    pub fn sequence(commands: impl IntoIterator<Item = Command<T>>) -> Self
+    where
+        T: 'static,
+    {
+        use iced_futures::futures::stream::{self, StreamExt};
+        use iced_futures::futures::FutureExt;
+
+        let steps: Vec<_> = commands
+            .into_iter()
+            .map(|command| {
+                stream::select_all(command.actions().into_iter().map(
+                    |action| match action {
+                        Action::Future(future) => {
+                            Box::pin(future.into_stream()) as _
+                        }
+                        Action::Stream(stream) => stream,
+                    },
+                ))
+            })
+            .collect();
+
+        Self::single(Action::Stream(Box::pin(stream::iter(steps).flatten())))
+    }
+
     /// Applies a transformation to the result of a [`Command`].
     #[cfg(target_arch = "wasm32")]
     pub fn map<A>(self, f: impl Fn(T) -> A + 'static + Clone) -> Command<A>
@@ -1,5 +1,6 @@
 pub enum Action<T> {
     Future(iced_futures::BoxFuture<T>),
+    Stream(iced_futures::BoxStream<T>),
 }
 
 use std::fmt;
@@ -11,10 +12,11 @@ impl<T> Action<T> {
     where
         T: 'static,
     {
-        use iced_futures::futures::FutureExt;
+        use iced_futures::futures::{FutureExt, StreamExt};
 
         match self {
             Self::Future(future) => Action::Future(Box::pin(future.map(f))),
+            Self::Stream(stream) => Action::Stream(Box::pin(stream.map(f))),
         }
     }
 }
@@ -23,6 +25,7 @@ impl<T> fmt::Debug for Action<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Future(_) => write!(f, "Action::Future"),
+            Self::Stream(_) => write!(f, "Action::Stream"),
         }
     }
 }
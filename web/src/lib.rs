@@ -398,6 +398,9 @@ fn run_command<Message: 'static + Send, E: Executor>(
             command::Action::Future(future) => {
                 runtime.spawn(future);
             }
+            command::Action::Stream(stream) => {
+                runtime.run(stream);
+            }
         }
     }
 }
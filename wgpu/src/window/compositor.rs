@@ -1,15 +1,18 @@
 use crate::{Backend, Color, Error, Renderer, Settings, Viewport};
 
+use futures::future::BoxFuture;
 use futures::task::SpawnExt;
 use iced_native::futures;
 use raw_window_handle::HasRawWindowHandle;
+use std::sync::Arc;
 
 /// A window graphics backend for iced powered by `wgpu`.
 #[allow(missing_debug_implementations)]
 pub struct Compositor {
     settings: Settings,
     instance: wgpu::Instance,
-    device: wgpu::Device,
+    adapter: wgpu::Adapter,
+    device: Arc<wgpu::Device>,
     queue: wgpu::Queue,
     staging_belt: wgpu::util::StagingBelt,
     local_pool: futures::executor::LocalPool,
@@ -71,7 +74,8 @@ impl Compositor {
         Some(Compositor {
             instance,
             settings,
-            device,
+            adapter,
+            device: Arc::new(device),
             queue,
             staging_belt,
             local_pool,
@@ -83,6 +87,52 @@ impl Compositor {
     pub fn create_backend(&self) -> Backend {
         Backend::new(&self.device, self.settings, self.format)
     }
+
+    /// Clears the given texture view with the background color and draws the
+    /// [`Renderer`] primitives on top of it.
+    fn draw<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Renderer,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &wgpu::TextureView,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) {
+        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("iced_wgpu::window::Compositor render pass"),
+            color_attachments: &[wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear({
+                        let [r, g, b, a] = background_color.into_linear();
+
+                        wgpu::Color {
+                            r: f64::from(r),
+                            g: f64::from(g),
+                            b: f64::from(b),
+                            a: f64::from(a),
+                        }
+                    }),
+                    store: true,
+                },
+            }],
+            depth_stencil_attachment: None,
+        });
+
+        renderer.with_primitives(|backend, primitives| {
+            backend.present(
+                &self.device,
+                &mut self.staging_belt,
+                encoder,
+                view,
+                primitives,
+                viewport,
+                overlay,
+            );
+        });
+    }
 }
 
 impl iced_graphics::window::Compositor for Compositor {
@@ -153,43 +203,14 @@ impl iced_graphics::window::Compositor for Compositor {
                     .texture
                     .create_view(&wgpu::TextureViewDescriptor::default());
 
-                let _ =
-                    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                        label: Some(
-                            "iced_wgpu::window::Compositor render pass",
-                        ),
-                        color_attachments: &[wgpu::RenderPassColorAttachment {
-                            view,
-                            resolve_target: None,
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear({
-                                    let [r, g, b, a] =
-                                        background_color.into_linear();
-
-                                    wgpu::Color {
-                                        r: f64::from(r),
-                                        g: f64::from(g),
-                                        b: f64::from(b),
-                                        a: f64::from(a),
-                                    }
-                                }),
-                                store: true,
-                            },
-                        }],
-                        depth_stencil_attachment: None,
-                    });
-
-                renderer.with_primitives(|backend, primitives| {
-                    backend.present(
-                        &mut self.device,
-                        &mut self.staging_belt,
-                        &mut encoder,
-                        view,
-                        primitives,
-                        viewport,
-                        overlay,
-                    );
-                });
+                self.draw(
+                    renderer,
+                    &mut encoder,
+                    view,
+                    viewport,
+                    background_color,
+                    overlay,
+                );
 
                 // Submit work
                 self.staging_belt.finish();
@@ -222,4 +243,150 @@ impl iced_graphics::window::Compositor for Compositor {
             },
         }
     }
+
+    fn screenshot<T: AsRef<str>>(
+        &mut self,
+        renderer: &mut Self::Renderer,
+        viewport: &Viewport,
+        background_color: Color,
+        overlay: &[T],
+    ) -> BoxFuture<'static, Vec<u8>> {
+        let size = viewport.physical_size();
+
+        if size.width == 0 || size.height == 0 {
+            return Box::pin(futures::future::ready(Vec::new()));
+        }
+
+        let extent = wgpu::Extent3d {
+            width: size.width,
+            height: size.height,
+            depth_or_array_layers: 1,
+        };
+
+        let texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu screenshot texture"),
+            size: extent,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: self.format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_SRC,
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        // Rows copied into a buffer must be padded to a fixed alignment
+        let bytes_per_row = size.width * 4;
+        let padded_bytes_per_row = {
+            let alignment = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+            let padding = (alignment - bytes_per_row % alignment) % alignment;
+
+            bytes_per_row + padding
+        };
+
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("iced_wgpu screenshot buffer"),
+            size: u64::from(padded_bytes_per_row * size.height),
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor {
+                label: Some("iced_wgpu screenshot encoder"),
+            },
+        );
+
+        self.draw(
+            renderer,
+            &mut encoder,
+            &view,
+            viewport,
+            background_color,
+            overlay,
+        );
+
+        encoder.copy_texture_to_buffer(
+            wgpu::ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: std::num::NonZeroU32::new(
+                        padded_bytes_per_row,
+                    ),
+                    rows_per_image: None,
+                },
+            },
+            extent,
+        );
+
+        // Submit work
+        self.staging_belt.finish();
+        self.queue.submit(Some(encoder.finish()));
+
+        // Recall staging buffers
+        self.local_pool
+            .spawner()
+            .spawn(self.staging_belt.recall())
+            .expect("Recall staging belt");
+
+        self.local_pool.run_until_stalled();
+
+        // Read back the pixels once the GPU is done, polling the device on
+        // another thread so the rendering loop is not blocked
+        let mapping = buffer.slice(..).map_async(wgpu::MapMode::Read);
+        let device = self.device.clone();
+
+        let _ = std::thread::spawn(move || device.poll(wgpu::Maintain::Wait));
+
+        let format = self.format;
+
+        Box::pin(async move {
+            if let Err(error) = mapping.await {
+                log::warn!("error reading screenshot: {:?}", error);
+
+                return Vec::new();
+            }
+
+            let mut bytes =
+                Vec::with_capacity((bytes_per_row * size.height) as usize);
+
+            {
+                let data = buffer.slice(..).get_mapped_range();
+
+                for row in data.chunks(padded_bytes_per_row as usize) {
+                    bytes.extend_from_slice(&row[..bytes_per_row as usize]);
+                }
+            }
+
+            buffer.unmap();
+
+            // Surfaces may prefer a BGRA format
+            if let wgpu::TextureFormat::Bgra8Unorm
+            | wgpu::TextureFormat::Bgra8UnormSrgb = format
+            {
+                for pixel in bytes.chunks_exact_mut(4) {
+                    pixel.swap(0, 2);
+                }
+            }
+
+            bytes
+        })
+    }
+
+    fn fetch_information(&self) -> iced_graphics::window::Information {
+        let information = self.adapter.get_info();
+
+        iced_graphics::window::Information {
+            adapter: information.name,
+            backend: format!("{:?}", information.backend),
+        }
+    }
 }
@@ -14,9 +14,11 @@ use crate::{
 use iced_futures::futures;
 use iced_futures::futures::channel::mpsc;
 use iced_graphics::window;
+use iced_native::command;
 use iced_native::program::Program;
 use iced_native::user_interface::{self, UserInterface};
 
+use std::fmt;
 use std::mem::ManuallyDrop;
 
 /// An interactive, native cross-platform application.
@@ -101,6 +103,38 @@ pub trait Application: Program {
     }
 }
 
+/// An event sent to the event loop of an [`Application`] by its runtime.
+pub enum UserEvent<Message> {
+    /// A message produced by a [`Command`] or a [`Subscription`].
+    Message(Message),
+
+    /// A step of a chained or sequenced [`Command`] that is ready to run.
+    ///
+    /// It carries the [`command::Completion`] of the step of a sequence the
+    /// [`Command`] belongs to, if any.
+    Continue(command::Continuation<Message>, Option<command::Completion>),
+
+    /// A request to close the window and exit the application.
+    Exit,
+
+    /// A request to capture a screenshot of the window and hand it to the
+    /// given closure, which sends its result back to the event loop.
+    Screenshot(Box<dyn FnOnce(iced_native::window::Screenshot) + Send>),
+}
+
+impl<Message: fmt::Debug> fmt::Debug for UserEvent<Message> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Message(message) => {
+                write!(f, "UserEvent::Message({:?})", message)
+            }
+            Self::Continue(..) => write!(f, "UserEvent::Continue"),
+            Self::Exit => write!(f, "UserEvent::Exit"),
+            Self::Screenshot(..) => write!(f, "UserEvent::Screenshot"),
+        }
+    }
+}
+
 /// Runs an [`Application`] with an executor, compositor, and the provided
 /// settings.
 pub fn run<A, E, C>(
@@ -151,17 +185,19 @@ where
 
     let mut clipboard = Clipboard::connect(&window);
 
+    let (compositor, renderer) = C::new(compositor_settings, Some(&window))?;
+
     run_command(
         init_command,
         &mut runtime,
         &mut clipboard,
         &mut proxy,
         &window,
+        &compositor.fetch_information(),
+        None,
     );
     runtime.track(subscription);
 
-    let (compositor, renderer) = C::new(compositor_settings, Some(&window))?;
-
     let (mut sender, receiver) = mpsc::unbounded();
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
@@ -222,9 +258,11 @@ async fn run_instance<A, E, C>(
     mut renderer: A::Renderer,
     mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
     mut clipboard: Clipboard,
-    mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
+    mut proxy: winit::event_loop::EventLoopProxy<UserEvent<A::Message>>,
     mut debug: Debug,
-    mut receiver: mpsc::UnboundedReceiver<winit::event::Event<'_, A::Message>>,
+    mut receiver: mpsc::UnboundedReceiver<
+        winit::event::Event<'_, UserEvent<A::Message>>,
+    >,
     window: winit::window::Window,
     exit_on_close_request: bool,
 ) where
@@ -256,15 +294,21 @@ async fn run_instance<A, E, C>(
         &mut debug,
     ));
 
+    let graphics_info = compositor.fetch_information();
     let mut mouse_interaction = mouse::Interaction::default();
     let mut events = Vec::new();
     let mut messages = Vec::new();
+    let mut exit_requested = false;
 
     debug.startup_finished();
 
     while let Some(event) = receiver.next().await {
         match event {
             event::Event::MainEventsCleared => {
+                if is_exit_ready(exit_requested, &messages) {
+                    break;
+                }
+
                 if events.is_empty() && messages.is_empty() {
                     continue;
                 }
@@ -303,12 +347,14 @@ async fn run_instance<A, E, C>(
                         &mut debug,
                         &mut messages,
                         &window,
+                        &graphics_info,
                     );
 
                     // Update window
                     state.synchronize(&application, &window);
 
-                    let should_exit = application.should_exit();
+                    let should_exit =
+                        exit_requested || application.should_exit();
 
                     user_interface = ManuallyDrop::new(build_user_interface(
                         &mut application,
@@ -348,9 +394,49 @@ async fn run_instance<A, E, C>(
                     )),
                 ));
             }
-            event::Event::UserEvent(message) => {
+            event::Event::UserEvent(UserEvent::Message(message)) => {
                 messages.push(message);
             }
+            event::Event::UserEvent(UserEvent::Continue(
+                continuation,
+                completion,
+            )) => {
+                let command = runtime.enter(continuation);
+
+                run_command(
+                    command,
+                    &mut runtime,
+                    &mut clipboard,
+                    &mut proxy,
+                    &window,
+                    &graphics_info,
+                    completion.as_ref(),
+                );
+            }
+            event::Event::UserEvent(UserEvent::Exit) => {
+                // Any events queued along with the request are still
+                // processed before exiting
+                exit_requested = true;
+            }
+            event::Event::UserEvent(UserEvent::Screenshot(deliver)) => {
+                let physical_size = state.physical_size();
+
+                let bytes = compositor.screenshot(
+                    &mut renderer,
+                    state.viewport(),
+                    state.background_color(),
+                    &debug.overlay(),
+                );
+
+                // The pixels are read back in the background
+                runtime.spawn_detached(Box::pin(async move {
+                    deliver(iced_native::window::Screenshot {
+                        width: physical_size.width,
+                        height: physical_size.height,
+                        bytes: bytes.await,
+                    });
+                }));
+            }
             event::Event::RedrawRequested(_) => {
                 let physical_size = state.physical_size();
 
@@ -439,6 +525,12 @@ async fn run_instance<A, E, C>(
                 ) {
                     events.push(event);
                 }
+
+                if let Some(event) =
+                    state.update_placement(&window, &window_event)
+                {
+                    events.push(crate::Event::Window(event));
+                }
             }
             _ => {}
         }
@@ -494,14 +586,17 @@ pub fn build_user_interface<'a, A: Application>(
 
 /// Updates an [`Application`] by feeding it the provided messages, spawning any
 /// resulting [`Command`], and tracking its [`Subscription`].
+// Every argument is needed to run the resulting commands in both shells
+#[allow(clippy::too_many_arguments)]
 pub fn update<A: Application, E: Executor>(
     application: &mut A,
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
     clipboard: &mut Clipboard,
-    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
+    proxy: &mut winit::event_loop::EventLoopProxy<UserEvent<A::Message>>,
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
     window: &winit::window::Window,
+    graphics_info: &window::Information,
 ) {
     for message in messages.drain(..) {
         debug.log_message(&message);
@@ -510,55 +605,355 @@ pub fn update<A: Application, E: Executor>(
         let command = runtime.enter(|| application.update(message));
         debug.update_finished();
 
-        run_command(command, runtime, clipboard, proxy, window);
+        run_command(
+            command,
+            runtime,
+            clipboard,
+            proxy,
+            window,
+            graphics_info,
+            None,
+        );
     }
 
     let subscription = application.subscription();
     runtime.track(subscription);
 }
 
+/// Returns whether an [`Application`] that requested to exit can do so.
+///
+/// The application only exits once every message produced before the request
+/// has been handled by [`Application::update`].
+pub fn is_exit_ready<Message>(
+    exit_requested: bool,
+    messages: &[Message],
+) -> bool {
+    exit_requested && messages.is_empty()
+}
+
 /// Runs the actions of a [`Command`].
+///
+/// If the [`Command`] is part of a step of a sequence, the given
+/// [`command::Completion`] is kept alive until all of its results have been
+/// produced.
 pub fn run_command<Message: 'static + std::fmt::Debug + Send, E: Executor>(
     command: Command<Message>,
     runtime: &mut Runtime<E, Proxy<Message>, Message>,
     clipboard: &mut Clipboard,
-    proxy: &mut winit::event_loop::EventLoopProxy<Message>,
+    proxy: &mut winit::event_loop::EventLoopProxy<UserEvent<Message>>,
     window: &winit::window::Window,
+    graphics_info: &iced_graphics::window::Information,
+    completion: Option<&command::Completion>,
 ) {
-    use iced_native::command;
-    use iced_native::window;
-
     for action in command.actions() {
         match action {
-            command::Action::Future(future) => {
-                runtime.spawn(future);
+            command::Action::Sequence(sequence) => {
+                let step = match sequence.next_step() {
+                    Some(step) => step,
+                    None => continue,
+                };
+
+                run_command(
+                    step.command,
+                    runtime,
+                    clipboard,
+                    proxy,
+                    window,
+                    graphics_info,
+                    Some(&step.completion),
+                );
+
+                let rest =
+                    Command::single(command::Action::Sequence(step.rest));
+
+                let completed = step.completed;
+                let completion = completion.cloned();
+                let proxy = proxy.clone();
+
+                // The completion of the step is dropped at the end of this
+                // arm, so it is only held by any work still running
+                runtime.spawn_detached(Box::pin(async move {
+                    completed.await;
+
+                    let _ = proxy.send_event(UserEvent::Continue(
+                        Box::new(move || rest),
+                        completion,
+                    ));
+                }));
+            }
+            command::Action::Chain(chain) => {
+                let deliver =
+                    sender(proxy, completion, |continuation, completion| {
+                        UserEvent::Continue(continuation, completion.cloned())
+                    });
+
+                resolve(
+                    chain.into_action(),
+                    runtime,
+                    clipboard,
+                    proxy,
+                    window,
+                    graphics_info,
+                    deliver,
+                );
             }
-            command::Action::Clipboard(action) => match action {
-                clipboard::Action::Read(tag) => {
-                    let message = tag(clipboard.read());
+            action => {
+                let deliver = sender(proxy, completion, |message, _| {
+                    UserEvent::Message(message)
+                });
+
+                resolve(
+                    action,
+                    runtime,
+                    clipboard,
+                    proxy,
+                    window,
+                    graphics_info,
+                    deliver,
+                );
+            }
+        }
+    }
+}
+
+/// Returns a closure that sends the outputs of an action to the event loop,
+/// wrapped in a [`UserEvent`] by `f`.
+///
+/// The closure holds on to the given [`command::Completion`], since a step of
+/// a sequence is only complete once all of its results have been sent.
+fn sender<T, Message: 'static + std::fmt::Debug + Send>(
+    proxy: &winit::event_loop::EventLoopProxy<UserEvent<Message>>,
+    completion: Option<&command::Completion>,
+    f: impl Fn(T, Option<&command::Completion>) -> UserEvent<Message>
+        + Send
+        + 'static,
+) -> impl Fn(T) + Send + 'static {
+    let proxy = proxy.clone();
+    let completion = completion.cloned();
+
+    move |output| {
+        let _ = proxy.send_event(f(output, completion.as_ref()));
+    }
+}
+
+/// Performs an action that is not a chain nor a sequence, handing each of its
+/// outputs to `deliver`.
+fn resolve<
+    T: 'static + Send,
+    Message: 'static + std::fmt::Debug + Send,
+    E: Executor,
+>(
+    action: command::Action<T>,
+    runtime: &mut Runtime<E, Proxy<Message>, Message>,
+    clipboard: &mut Clipboard,
+    proxy: &winit::event_loop::EventLoopProxy<UserEvent<Message>>,
+    window: &winit::window::Window,
+    graphics_info: &iced_graphics::window::Information,
+    deliver: impl Fn(T) + Send + 'static,
+) {
+    use iced_native::system;
+    use iced_native::window;
 
-                    proxy
-                        .send_event(message)
-                        .expect("Send message to event loop");
+    match action {
+        command::Action::Future(future) => {
+            runtime.spawn_detached(Box::pin(async move {
+                let output = future.await;
+
+                deliver(output);
+            }));
+        }
+        command::Action::Stream(stream) => {
+            use iced_futures::futures::{future, StreamExt};
+
+            runtime.spawn_detached(Box::pin(stream.for_each(move |output| {
+                deliver(output);
+
+                future::ready(())
+            })));
+        }
+        command::Action::Clipboard(action) => match action {
+            clipboard::Action::Read(tag) => {
+                deliver(tag(clipboard.read()));
+            }
+            clipboard::Action::Write(contents, None) => {
+                clipboard.write(contents);
+            }
+            clipboard::Action::Write(contents, Some(tag)) => {
+                deliver(tag(clipboard.try_write(contents)));
+            }
+            clipboard::Action::ReadImage(tag) => {
+                deliver(tag(clipboard.read_image()));
+            }
+            clipboard::Action::WriteImage(image) => {
+                clipboard.write_image(image);
+            }
+            clipboard::Action::ReadHtml(tag) => {
+                deliver(tag(clipboard.read_html()));
+            }
+            clipboard::Action::WriteHtml { html, alt_text } => {
+                clipboard.write_html(html, alt_text);
+            }
+            clipboard::Action::ReadFiles(tag) => {
+                deliver(tag(clipboard.read_files()));
+            }
+            clipboard::Action::WriteFiles(files) => {
+                clipboard.write_files(files);
+            }
+        },
+        command::Action::Window(action) => match action {
+            window::Action::Resize { width, height } => {
+                window
+                    .set_inner_size(winit::dpi::LogicalSize { width, height });
+            }
+            window::Action::Move { x, y } => {
+                window.set_outer_position(winit::dpi::LogicalPosition { x, y });
+            }
+            window::Action::SetPosition { x, y } => {
+                window
+                    .set_outer_position(winit::dpi::PhysicalPosition { x, y });
+            }
+            window::Action::Center { monitor } => {
+                let monitor = match monitor {
+                    Some(index) => window.available_monitors().nth(index),
+                    None => window.current_monitor(),
+                };
+
+                if let Some(monitor) = monitor {
+                    let position = monitor.position();
+                    let monitor_size = monitor.size();
+                    let window_size = window.outer_size();
+
+                    window.set_outer_position(winit::dpi::PhysicalPosition {
+                        x: position.x
+                            + (monitor_size.width as i32
+                                - window_size.width as i32)
+                                / 2,
+                        y: position.y
+                            + (monitor_size.height as i32
+                                - window_size.height as i32)
+                                / 2,
+                    });
                 }
-                clipboard::Action::Write(contents) => {
-                    clipboard.write(contents);
+            }
+            window::Action::Close => {
+                proxy
+                    .send_event(UserEvent::Exit)
+                    .expect("Send exit request to event loop");
+            }
+            window::Action::SetTitle(title) => {
+                window.set_title(&title);
+            }
+            window::Action::Minimize => {
+                window.set_minimized(true);
+            }
+            window::Action::Maximize(maximized) => {
+                if window.fullscreen().is_none() {
+                    window.set_maximized(maximized);
                 }
-            },
-            command::Action::Window(action) => match action {
-                window::Action::Resize { width, height } => {
-                    window.set_inner_size(winit::dpi::LogicalSize {
-                        width,
-                        height,
-                    });
+            }
+            window::Action::ToggleMaximize => {
+                if window.fullscreen().is_none() {
+                    window.set_maximized(!window.is_maximized());
                 }
-                window::Action::Move { x, y } => {
-                    window.set_outer_position(winit::dpi::LogicalPosition {
-                        x,
-                        y,
-                    });
+            }
+            window::Action::Drag => {
+                let _ = window.drag_window();
+            }
+            window::Action::SetIcon(icon) => {
+                if let Some(icon) = conversion::icon(icon) {
+                    window.set_window_icon(Some(icon));
                 }
-            },
+            }
+            window::Action::SetAlwaysOnTop(always_on_top) => {
+                window.set_always_on_top(always_on_top);
+            }
+            window::Action::SetMinSize(size) => {
+                window.set_min_inner_size(size.map(|(width, height)| {
+                    winit::dpi::LogicalSize { width, height }
+                }));
+            }
+            window::Action::SetMaxSize(size) => {
+                window.set_max_inner_size(size.map(|(width, height)| {
+                    winit::dpi::LogicalSize { width, height }
+                }));
+            }
+            window::Action::SetResizable(resizable) => {
+                window.set_resizable(resizable);
+            }
+            window::Action::RequestUserAttention(attention) => {
+                window.request_user_attention(
+                    attention.map(conversion::user_attention),
+                );
+            }
+            window::Action::FetchState(tag) => {
+                deliver(tag(conversion::window_state(window)));
+            }
+            window::Action::FetchMonitors(tag) => {
+                deliver(tag(monitors(window)));
+            }
+            window::Action::Screenshot(tag) => {
+                proxy
+                    .send_event(UserEvent::Screenshot(Box::new(
+                        move |screenshot| deliver(tag(screenshot)),
+                    )))
+                    .expect("Send screenshot request to event loop");
+            }
+        },
+        command::Action::System(action) => match action {
+            system::Action::QueryInformation(tag) => {
+                let graphics_info = graphics_info.clone();
+
+                let _ = std::thread::spawn(move || {
+                    deliver(tag(crate::system::information(graphics_info)));
+                });
+            }
+        },
+        command::Action::Chain(_) | command::Action::Sequence(_) => {
+            // `run_command` takes care of these, and chains only ever wrap
+            // actions that produce outputs
+            unreachable!("Chains and sequences cannot be resolved directly")
         }
     }
 }
+
+/// Lists the monitors available to the given window.
+fn monitors(
+    window: &winit::window::Window,
+) -> Vec<iced_native::window::Monitor> {
+    let primary = window.primary_monitor();
+
+    window
+        .available_monitors()
+        .map(|monitor| conversion::monitor(monitor, primary.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_exit_ready, UserEvent};
+
+    #[test]
+    fn exit_waits_for_messages_queued_with_close() {
+        // `Command::batch([window::close(), clipboard::read(..)])` queues the
+        // exit request before the contents of the clipboard
+        let queue = vec![UserEvent::Exit, UserEvent::Message("contents")];
+
+        let mut exit_requested = false;
+        let mut messages = Vec::new();
+
+        for event in queue {
+            match event {
+                UserEvent::Exit => exit_requested = true,
+                UserEvent::Message(message) => messages.push(message),
+                event => panic!("Unexpected event: {:?}", event),
+            }
+        }
+
+        assert!(!is_exit_ready(exit_requested, &messages));
+
+        // `update` handles the contents
+        messages.clear();
+
+        assert!(is_exit_ready(exit_requested, &messages));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::conversion;
+use crate::window;
 use crate::{Application, Color, Debug, Mode, Point, Size, Viewport};
 
 use std::marker::PhantomData;
@@ -16,9 +17,18 @@ pub struct State<A: Application> {
     viewport_version: usize,
     cursor_position: winit::dpi::PhysicalPosition<f64>,
     modifiers: winit::event::ModifiersState,
+    placement: Placement,
     application: PhantomData<A>,
 }
 
+/// Whether a window is maximized, minimized, or neither.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Placement {
+    Normal,
+    Maximized,
+    Minimized,
+}
+
 impl<A: Application> State<A> {
     /// Creates a new [`State`] for the provided [`Application`] and window.
     pub fn new(application: &A, window: &Window) -> Self {
@@ -46,6 +56,11 @@ impl<A: Application> State<A> {
             // TODO: Encode cursor availability in the type-system
             cursor_position: winit::dpi::PhysicalPosition::new(-1.0, -1.0),
             modifiers: winit::event::ModifiersState::default(),
+            placement: if window.is_maximized() {
+                Placement::Maximized
+            } else {
+                Placement::Normal
+            },
             application: PhantomData,
         }
     }
@@ -156,6 +171,43 @@ impl<A: Application> State<A> {
         }
     }
 
+    /// Processes the provided window event and returns the [`window::Event`]
+    /// describing whether the window was maximized, minimized, or restored,
+    /// if it changed.
+    ///
+    /// A window is considered minimized when it is resized to zero, since
+    /// most platforms do not report minimization otherwise.
+    pub fn update_placement(
+        &mut self,
+        window: &Window,
+        event: &WindowEvent<'_>,
+    ) -> Option<window::Event> {
+        let new_size = match event {
+            WindowEvent::Resized(new_size) => new_size,
+            _ => return None,
+        };
+
+        let placement = if new_size.width == 0 || new_size.height == 0 {
+            Placement::Minimized
+        } else if window.is_maximized() {
+            Placement::Maximized
+        } else {
+            Placement::Normal
+        };
+
+        if self.placement == placement {
+            return None;
+        }
+
+        self.placement = placement;
+
+        Some(match placement {
+            Placement::Normal => window::Event::Restored,
+            Placement::Maximized => window::Event::Maximized,
+            Placement::Minimized => window::Event::Minimized,
+        })
+    }
+
     /// Synchronizes the [`State`] with its [`Application`] and its respective
     /// window.
     ///
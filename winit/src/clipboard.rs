@@ -1,13 +1,21 @@
 //! Access the clipboard.
-pub use iced_native::clipboard::Action;
+pub use iced_native::clipboard::{Action, Error, ImageData};
 
 use crate::command::{self, Command};
 
+use std::path::PathBuf;
+
 /// A buffer for short-term storage and transfer within and between
 /// applications.
 #[allow(missing_debug_implementations)]
 pub struct Clipboard {
     state: State,
+    #[cfg(any(
+        feature = "clipboard_image",
+        feature = "clipboard_html",
+        feature = "clipboard_files"
+    ))]
+    rich: Option<arboard::Clipboard>,
 }
 
 enum State {
@@ -23,7 +31,15 @@ impl Clipboard {
             .map(State::Connected)
             .unwrap_or(State::Unavailable);
 
-        Clipboard { state }
+        Clipboard {
+            state,
+            #[cfg(any(
+                feature = "clipboard_image",
+                feature = "clipboard_html",
+                feature = "clipboard_files"
+            ))]
+            rich: arboard::Clipboard::new().ok(),
+        }
     }
 
     /// Reads the current content of the [`Clipboard`] as text.
@@ -36,14 +52,167 @@ impl Clipboard {
 
     /// Writes the given text contents to the [`Clipboard`].
     pub fn write(&mut self, contents: String) {
+        match self.try_write(contents) {
+            Ok(()) | Err(Error::Unsupported) => {}
+            Err(error) => {
+                log::warn!("error writing to clipboard: {}", error)
+            }
+        }
+    }
+
+    /// Writes the given text contents to the [`Clipboard`], returning
+    /// whether the operation succeeded.
+    ///
+    /// The focus of the window is not tracked, so [`Error::NotFocused`] is
+    /// never returned.
+    pub fn try_write(&mut self, contents: String) -> Result<(), Error> {
         match &mut self.state {
-            State::Connected(clipboard) => match clipboard.write(contents) {
-                Ok(()) => {}
-                Err(error) => {
-                    log::warn!("error writing to clipboard: {}", error)
+            State::Connected(clipboard) => clipboard
+                .write(contents)
+                .map_err(|error| Error::Backend(error.to_string())),
+            State::Unavailable => Err(Error::Unsupported),
+        }
+    }
+
+    /// Reads the current content of the [`Clipboard`] as an image.
+    ///
+    /// Images are only supported when the `clipboard_image` feature is
+    /// enabled. Otherwise, this always returns `None`.
+    pub fn read_image(&mut self) -> Option<ImageData> {
+        #[cfg(feature = "clipboard_image")]
+        {
+            let image = self.rich.as_mut()?.get_image().ok()?;
+
+            Some(ImageData {
+                width: image.width,
+                height: image.height,
+                bytes: image.bytes.into_owned(),
+            })
+        }
+
+        #[cfg(not(feature = "clipboard_image"))]
+        {
+            None
+        }
+    }
+
+    /// Writes the given image to the [`Clipboard`].
+    ///
+    /// Images are only supported when the `clipboard_image` feature is
+    /// enabled. Otherwise, this does nothing and logs a warning.
+    pub fn write_image(&mut self, image: ImageData) {
+        #[cfg(feature = "clipboard_image")]
+        {
+            if let Some(rich) = &mut self.rich {
+                let image = arboard::ImageData {
+                    width: image.width,
+                    height: image.height,
+                    bytes: image.bytes.into(),
+                };
+
+                if let Err(error) = rich.set_image(image) {
+                    log::warn!("error writing image to clipboard: {}", error)
                 }
-            },
-            State::Unavailable => {}
+            } else {
+                log::warn!("image clipboard is unavailable")
+            }
+        }
+
+        #[cfg(not(feature = "clipboard_image"))]
+        {
+            let _ = image;
+
+            log::warn!("writing images to the clipboard is not supported")
+        }
+    }
+
+    /// Reads the current content of the [`Clipboard`] as HTML.
+    ///
+    /// HTML is only supported when the `clipboard_html` feature is enabled.
+    /// Otherwise, this always returns `None`.
+    pub fn read_html(&mut self) -> Option<String> {
+        #[cfg(feature = "clipboard_html")]
+        {
+            self.rich.as_mut()?.get().html().ok()
+        }
+
+        #[cfg(not(feature = "clipboard_html"))]
+        {
+            None
+        }
+    }
+
+    /// Writes the given HTML to the [`Clipboard`], along with an optional
+    /// plain text alternative.
+    ///
+    /// HTML is only supported when the `clipboard_html` feature is enabled.
+    /// Otherwise, or if the HTML cannot be written, only the plain text
+    /// alternative is written, falling back to the HTML itself.
+    pub fn write_html(&mut self, html: String, alt_text: Option<String>) {
+        #[cfg(feature = "clipboard_html")]
+        {
+            match &mut self.rich {
+                Some(rich) => {
+                    match rich.set_html(html.as_str(), alt_text.as_deref()) {
+                        Ok(()) => return,
+                        Err(error) => log::warn!(
+                            "error writing HTML to clipboard, writing text \
+                            instead: {}",
+                            error
+                        ),
+                    }
+                }
+                None => log::warn!(
+                    "HTML clipboard is unavailable, writing text instead"
+                ),
+            }
+        }
+
+        self.write(alt_text.unwrap_or(html))
+    }
+
+    /// Reads the list of files currently stored in the [`Clipboard`].
+    ///
+    /// The list is empty if the [`Clipboard`] does not contain any files.
+    ///
+    /// File lists are only supported when the `clipboard_files` feature is
+    /// enabled. Otherwise, this always returns an empty list.
+    pub fn read_files(&mut self) -> Vec<PathBuf> {
+        #[cfg(feature = "clipboard_files")]
+        {
+            self.rich
+                .as_mut()
+                .and_then(|rich| rich.get().file_list().ok())
+                .unwrap_or_default()
+        }
+
+        #[cfg(not(feature = "clipboard_files"))]
+        {
+            Vec::new()
+        }
+    }
+
+    /// Writes the given list of files to the [`Clipboard`].
+    ///
+    /// File lists are only supported when the `clipboard_files` feature is
+    /// enabled. Otherwise, this does nothing and logs a warning.
+    pub fn write_files(&mut self, files: Vec<PathBuf>) {
+        #[cfg(feature = "clipboard_files")]
+        {
+            if let Some(rich) = &mut self.rich {
+                if let Err(error) = rich.set().file_list(&files) {
+                    log::warn!("error writing files to clipboard: {}", error)
+                }
+            } else {
+                log::warn!("file clipboard is unavailable")
+            }
+        }
+
+        #[cfg(not(feature = "clipboard_files"))]
+        {
+            let _ = files;
+
+            log::warn!("writing files to the clipboard is not supported")
         }
     }
 }
@@ -60,12 +229,67 @@ impl iced_native::Clipboard for Clipboard {
 
 /// Read the current contents of the clipboard.
 pub fn read<Message>(
-    f: impl Fn(Option<String>) -> Message + 'static,
+    f: impl Fn(Option<String>) -> Message + 'static + Send,
 ) -> Command<Message> {
     Command::single(command::Action::Clipboard(Action::Read(Box::new(f))))
 }
 
 /// Write the given contents to the clipboard.
 pub fn write<Message>(contents: String) -> Command<Message> {
-    Command::single(command::Action::Clipboard(Action::Write(contents)))
+    Command::single(command::Action::Clipboard(Action::Write(contents, None)))
+}
+
+/// Write the given contents to the clipboard and produce a `Message` with
+/// the result.
+pub fn write_with_result<Message>(
+    contents: String,
+    f: impl FnOnce(Result<(), Error>) -> Message + 'static + Send,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::Write(
+        contents,
+        Some(Box::new(f)),
+    )))
+}
+
+/// Read the current contents of the clipboard as an image.
+pub fn read_image<Message>(
+    f: impl Fn(Option<ImageData>) -> Message + 'static + Send,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadImage(Box::new(f))))
+}
+
+/// Write the given image to the clipboard.
+pub fn write_image<Message>(image: ImageData) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WriteImage(image)))
+}
+
+/// Read the current contents of the clipboard as HTML.
+pub fn read_html<Message>(
+    f: impl Fn(Option<String>) -> Message + 'static + Send,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadHtml(Box::new(f))))
+}
+
+/// Write the given HTML to the clipboard, along with an optional plain text
+/// alternative.
+pub fn write_html<Message>(
+    html: String,
+    alt_text: Option<String>,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WriteHtml {
+        html,
+        alt_text,
+    }))
+}
+
+/// Read the list of files currently stored in the clipboard.
+pub fn read_files<Message>(
+    f: impl Fn(Vec<PathBuf>) -> Message + 'static + Send,
+) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::ReadFiles(Box::new(f))))
+}
+
+/// Write the given list of files to the clipboard.
+pub fn write_files<Message>(files: Vec<PathBuf>) -> Command<Message> {
+    Command::single(command::Action::Clipboard(Action::WriteFiles(files)))
 }
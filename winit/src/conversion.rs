@@ -205,6 +205,101 @@ pub fn visible(mode: Mode) -> bool {
     }
 }
 
+/// Converts a window [`Icon`] from [`iced_native`] to a [`winit`] icon.
+///
+/// Logs a warning and returns `None` if the underlying OS fails to create the
+/// icon.
+///
+/// [`Icon`]: window::Icon
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn icon(icon: window::Icon) -> Option<winit::window::Icon> {
+    let (rgba, width, height) = icon.into_raw();
+
+    match winit::window::Icon::from_rgba(rgba, width, height) {
+        Ok(icon) => Some(icon),
+        Err(error) => {
+            log::warn!("Failed to create window icon: {}", error);
+
+            None
+        }
+    }
+}
+
+/// Converts a [`UserAttention`] from [`iced_native`] to a [`winit`] user
+/// attention type.
+///
+/// [`UserAttention`]: window::UserAttention
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn user_attention(
+    attention: window::UserAttention,
+) -> winit::window::UserAttentionType {
+    match attention {
+        window::UserAttention::Critical => {
+            winit::window::UserAttentionType::Critical
+        }
+        window::UserAttention::Informational => {
+            winit::window::UserAttentionType::Informational
+        }
+    }
+}
+
+/// Builds the current [`State`] of a [`winit`] window.
+///
+/// A window is considered minimized when its size is zero, since [`winit`]
+/// cannot query minimization.
+///
+/// [`State`]: window::State
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn window_state(window: &winit::window::Window) -> window::State {
+    let scale_factor = window.scale_factor();
+    let size = window.inner_size();
+    let logical_size = size.to_logical(scale_factor);
+
+    window::State {
+        width: logical_size.width,
+        height: logical_size.height,
+        position: window.outer_position().ok().map(|position| {
+            let position = position.to_logical(scale_factor);
+
+            (position.x, position.y)
+        }),
+        scale_factor,
+        is_maximized: window.is_maximized(),
+        is_minimized: size.width == 0 || size.height == 0,
+        is_fullscreen: window.fullscreen().is_some(),
+        monitor: window
+            .current_monitor()
+            .map(|monitor| self::monitor(monitor, window.primary_monitor())),
+    }
+}
+
+/// Converts a [`winit`] monitor handle into a [`Monitor`].
+///
+/// The monitor is considered primary if it is equal to the given `primary`
+/// monitor.
+///
+/// [`Monitor`]: window::Monitor
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn monitor(
+    monitor: winit::monitor::MonitorHandle,
+    primary: Option<winit::monitor::MonitorHandle>,
+) -> window::Monitor {
+    let position = monitor.position();
+    let size = monitor.size();
+
+    window::Monitor {
+        name: monitor.name(),
+        x: position.x,
+        y: position.y,
+        width: size.width,
+        height: size.height,
+        scale_factor: monitor.scale_factor(),
+        is_primary: primary.as_ref() == Some(&monitor),
+    }
+}
+
 /// Converts a `MouseCursor` from [`iced_native`] to a [`winit`] cursor icon.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
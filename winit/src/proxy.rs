@@ -1,3 +1,5 @@
+use crate::application::UserEvent;
+
 use iced_native::futures::{
     channel::mpsc,
     task::{Context, Poll},
@@ -8,7 +10,7 @@ use std::pin::Pin;
 /// An event loop proxy that implements `Sink`.
 #[derive(Debug)]
 pub struct Proxy<Message: 'static> {
-    raw: winit::event_loop::EventLoopProxy<Message>,
+    raw: winit::event_loop::EventLoopProxy<UserEvent<Message>>,
 }
 
 impl<Message: 'static> Clone for Proxy<Message> {
@@ -21,7 +23,9 @@ impl<Message: 'static> Clone for Proxy<Message> {
 
 impl<Message: 'static> Proxy<Message> {
     /// Creates a new [`Proxy`] from an `EventLoopProxy`.
-    pub fn new(raw: winit::event_loop::EventLoopProxy<Message>) -> Self {
+    pub fn new(
+        raw: winit::event_loop::EventLoopProxy<UserEvent<Message>>,
+    ) -> Self {
         Self { raw }
     }
 }
@@ -40,7 +44,7 @@ impl<Message: 'static> Sink<Message> for Proxy<Message> {
         self: Pin<&mut Self>,
         message: Message,
     ) -> Result<(), Self::Error> {
-        let _ = self.raw.send_event(message);
+        let _ = self.raw.send_event(UserEvent::Message(message));
 
         Ok(())
     }
@@ -0,0 +1,45 @@
+//! Access the native system.
+use crate::command::{self, Command};
+pub use iced_native::system::*;
+
+use iced_graphics::window;
+
+/// Queries for available system information.
+///
+/// The information will be `None` if it could not be gathered, which is
+/// always the case when the `system` feature is disabled.
+pub fn fetch_information<Message>(
+    f: impl FnOnce(Option<Information>) -> Message + Send + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::System(Action::QueryInformation(
+        Box::new(f),
+    )))
+}
+
+#[cfg(feature = "system")]
+pub(crate) fn information(
+    graphics_info: window::Information,
+) -> Option<Information> {
+    use sysinfo::{ProcessorExt, RefreshKind, System, SystemExt};
+
+    let system =
+        System::new_with_specifics(RefreshKind::new().with_cpu().with_memory());
+
+    Some(Information {
+        system_name: system.name(),
+        system_kernel: system.kernel_version(),
+        system_version: system.long_os_version(),
+        cpu_brand: system.global_processor_info().brand().into(),
+        cpu_cores: system.physical_core_count(),
+        memory_total: system.total_memory(),
+        graphics_adapter: graphics_info.adapter,
+        graphics_backend: graphics_info.backend,
+    })
+}
+
+#[cfg(not(feature = "system"))]
+pub(crate) fn information(
+    _graphics_info: window::Information,
+) -> Option<Information> {
+    None
+}
@@ -2,7 +2,9 @@
 use crate::command::{self, Command};
 use iced_native::window;
 
-pub use window::Event;
+pub use window::{
+    icon, Event, Icon, Monitor, Screenshot, State, UserAttention,
+};
 
 /// Resizes the window to the given logical dimensions.
 pub fn resize<Message>(width: u32, height: u32) -> Command<Message> {
@@ -16,3 +18,159 @@ pub fn resize<Message>(width: u32, height: u32) -> Command<Message> {
 pub fn move_to<Message>(x: i32, y: i32) -> Command<Message> {
     Command::single(command::Action::Window(window::Action::Move { x, y }))
 }
+
+/// Moves the window to the given physical coordinates.
+pub fn set_position<Message>(x: i32, y: i32) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetPosition {
+        x,
+        y,
+    }))
+}
+
+/// Centers the window on a monitor.
+///
+/// The monitor is an index into the list produced by [`fetch_monitors`]. If
+/// `None`, the current monitor of the window is used.
+pub fn center<Message>(monitor: Option<usize>) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Center { monitor }))
+}
+
+/// Closes the window and exits the application.
+///
+/// The application will exit once the commands of the current update have
+/// been run. Any message that is already waiting to be processed, like the
+/// result of a clipboard read batched with this command, is fed to `update`
+/// first.
+///
+/// This exits the same way as returning `true` from
+/// [`Application::should_exit`], which is checked after every update.
+///
+/// [`Application::should_exit`]: crate::Application::should_exit
+pub fn close<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Close))
+}
+
+/// Sets the title of the window.
+///
+/// The new title stays in place until the title of the [`Application`]
+/// changes.
+///
+/// [`Application`]: crate::Application
+pub fn set_title<Message>(title: String) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetTitle(title)))
+}
+
+/// Minimizes the window.
+pub fn minimize<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Minimize))
+}
+
+/// Maximizes the window if `maximized` is true, or restores it otherwise.
+///
+/// This has no effect while the window is fullscreen.
+pub fn maximize<Message>(maximized: bool) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Maximize(
+        maximized,
+    )))
+}
+
+/// Maximizes the window if it is not maximized, or restores it otherwise.
+///
+/// This has no effect while the window is fullscreen.
+pub fn toggle_maximize<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::ToggleMaximize))
+}
+
+/// Starts dragging the window with the left mouse button until it is
+/// released.
+///
+/// This is useful to move windows without decorations. It only has an effect
+/// when issued in response to a press of the left mouse button.
+pub fn drag<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Drag))
+}
+
+/// Sets the icon of the window.
+///
+/// This has no effect on macOS, where windows do not have icons.
+pub fn set_icon<Message>(icon: impl Into<Icon>) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetIcon(
+        icon.into(),
+    )))
+}
+
+/// Sets whether the window should always be on top of other windows.
+pub fn set_always_on_top<Message>(always_on_top: bool) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetAlwaysOnTop(
+        always_on_top,
+    )))
+}
+
+/// Sets the minimum logical size of the window, or removes the constraint if
+/// `None`.
+pub fn set_min_size<Message>(size: Option<(u32, u32)>) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetMinSize(size)))
+}
+
+/// Sets the maximum logical size of the window, or removes the constraint if
+/// `None`.
+pub fn set_max_size<Message>(size: Option<(u32, u32)>) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetMaxSize(size)))
+}
+
+/// Sets whether the window can be resized by the user.
+pub fn set_resizable<Message>(resizable: bool) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::SetResizable(
+        resizable,
+    )))
+}
+
+/// Requests the attention of the user, or clears a pending request if
+/// `None`.
+///
+/// This is useful to notify the user about events that happen while the
+/// window is not focused. The request is cleared automatically once the
+/// window is focused.
+pub fn request_attention<Message>(
+    attention: Option<UserAttention>,
+) -> Command<Message> {
+    Command::single(command::Action::Window(
+        window::Action::RequestUserAttention(attention),
+    ))
+}
+
+/// Fetches the current [`State`] of the window and produces a `Message` with
+/// it.
+pub fn fetch_state<Message>(
+    f: impl FnOnce(State) -> Message + 'static + Send,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::FetchState(
+        Box::new(f),
+    )))
+}
+
+/// Fetches the monitors connected to the system and produces a `Message`
+/// with them.
+pub fn fetch_monitors<Message>(
+    f: impl FnOnce(Vec<Monitor>) -> Message + 'static + Send,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::FetchMonitors(
+        Box::new(f),
+    )))
+}
+
+/// Captures a [`Screenshot`] of the window and produces a `Message` with it.
+///
+/// The screenshot is rendered again from the current state of the user
+/// interface, so it may differ from the last frame presented to the window.
+///
+/// With `wgpu`, the pixels are read back from the GPU in the background, so
+/// the application keeps running until the `Message` is produced. With
+/// OpenGL, reading them back blocks the event loop.
+pub fn screenshot<Message>(
+    f: impl FnOnce(Screenshot) -> Message + 'static + Send,
+) -> Command<Message> {
+    Command::single(command::Action::Window(window::Action::Screenshot(
+        Box::new(f),
+    )))
+}